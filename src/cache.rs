@@ -43,7 +43,7 @@ pub fn set(source: &str, package: &str, version: &str) {
     let path = dir.join(format!("{}-{}.json", source, sanitize(package)));
     let entry = CacheEntry {
         version: version.to_string(),
-        timestamp: SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0),
+        timestamp: SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs()),
     };
 
     if let Ok(content) = serde_json::to_string(&entry) {
@@ -1,16 +1,21 @@
 use crate::sources::{SourceType, default_precedence};
 use serde::Deserialize;
 use std::fs;
+use std::path::PathBuf;
 
 #[derive(Deserialize)]
 pub struct Config {
     #[serde(default = "default_precedence")]
     pub precedence: Vec<SourceType>,
+    /// Local release cycle data (endoflife.date schema, keyed by product) to use
+    /// instead of the endoflife.date API
+    #[serde(default)]
+    pub eol_data: Option<PathBuf>,
 }
 
 impl Default for Config {
     fn default() -> Self {
-        Self { precedence: default_precedence() }
+        Self { precedence: default_precedence(), eol_data: None }
     }
 }
 
@@ -29,7 +34,7 @@ impl Config {
 mod tests {
     use super::*;
     #[test]
-    fn test_default_has_all_sources() {
+    fn test_default_has_default_sources() {
         let config = Config::default();
         assert_eq!(config.precedence.len(), 18);
     }
@@ -38,5 +43,10 @@ mod tests {
     fn test_parse_config() {
         let config: Config = toml::from_str(r#"precedence = ["npm", "cargo"]"#).unwrap();
         assert_eq!(config.precedence.len(), 2);
+        assert_eq!(config.eol_data, None);
+
+        let config: Config = toml::from_str(r#"eol_data = "/srv/eol.json""#).unwrap();
+        assert_eq!(config.precedence.len(), 18);
+        assert_eq!(config.eol_data, Some(PathBuf::from("/srv/eol.json")));
     }
 }
@@ -2,6 +2,7 @@
 
 pub mod cache;
 pub mod config;
+pub mod lifecycle;
 pub mod project;
 pub mod sources;
 
@@ -84,7 +85,7 @@ mod proptests {
             patch in 0u32..100,
             incr in 1u32..10
         ) {
-            let base = format!("{}.{}.{}", major, minor, patch);
+            let base = format!("{major}.{minor}.{patch}");
             let newer_patch = format!("{}.{}.{}", major, minor, patch + incr);
             let newer_minor = format!("{}.{}.{}", major, minor + incr, patch);
             let newer_major = format!("{}.{}.{}", major + incr, minor, patch);
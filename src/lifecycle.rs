@@ -0,0 +1,248 @@
+//! Release cycle support windows, in the endoflife.date schema: from its API, or
+//! from a local JSON file for air-gapped use
+
+use crate::cache;
+use serde::Serialize;
+use serde_json::Value;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Sources a runtime or server can be installed from or pinned to
+const SYSTEM: &[&str] = &[
+    "path", "brew", "apt", "images", "docker", "node", "python", "ruby", "rust", "golang", "java",
+];
+
+/// Packages tracked by endoflife.date, the product they're tracked under, and the
+/// sources where the name means that product (npm's `redis` is a client, not the server)
+const PRODUCTS: &[(&str, &str, &[&str])] = &[
+    ("node", "nodejs", SYSTEM),
+    ("nodejs", "nodejs", SYSTEM),
+    ("python", "python", SYSTEM),
+    ("python3", "python", SYSTEM),
+    ("ruby", "ruby", SYSTEM),
+    ("go", "go", SYSTEM),
+    ("golang", "go", SYSTEM),
+    ("rust", "rust", SYSTEM),
+    ("rustc", "rust", SYSTEM),
+    ("java", "eclipse-temurin", SYSTEM),
+    ("eclipse-temurin", "eclipse-temurin", SYSTEM),
+    ("php", "php", SYSTEM),
+    ("postgres", "postgresql", SYSTEM),
+    ("mysql", "mysql", SYSTEM),
+    ("mariadb", "mariadb", SYSTEM),
+    ("redis", "redis", SYSTEM),
+    ("mongo", "mongodb", SYSTEM),
+    ("nginx", "nginx", SYSTEM),
+    ("django", "django", &["pypi", "pip", "uv"]),
+    ("rails", "rails", &["gem"]),
+    ("laravel/framework", "laravel", &["composer"]),
+    ("react", "react", &["npm"]),
+    ("vue", "vue", &["npm"]),
+    ("@angular/core", "angular", &["npm"]),
+];
+
+/// Where a release cycle stands in its support window
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Lifecycle {
+    /// The release cycle the version belongs to (`20`, `3.12`)
+    pub cycle: String,
+    pub lts: bool,
+    /// End of active support, after which only security fixes land
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub support_end: Option<String>,
+    pub support_ended: bool,
+    /// End of security support
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub eol: Option<String>,
+    pub end_of_life: bool,
+}
+
+/// The endoflife.date product for a package found in `source`, ignoring any release
+/// channel (`node@lts`) and image registry path (`docker.io/library/postgres`)
+#[must_use]
+pub fn product(package: &str, source: &str) -> Option<&'static str> {
+    let name = package.split_once('@').filter(|(n, _)| !n.is_empty()).map_or(package, |(n, _)| n);
+    let name = name.to_ascii_lowercase();
+    let image = if matches!(source, "docker" | "images") { name.rsplit('/').next() } else { None };
+    PRODUCTS
+        .iter()
+        .filter(|(_, _, sources)| sources.contains(&source))
+        .find(|(package, _, _)| *package == name || image == Some(package))
+        .map(|(_, product, _)| *product)
+}
+
+/// Support status of `version` of `package`, as found in `source`. `data` is a local
+/// JSON file mapping each product to its cycles, used instead of the API when set
+#[must_use]
+pub fn lookup(
+    package: &str,
+    source: &str,
+    version: &str,
+    data: Option<&Path>,
+    use_cache: bool,
+) -> Option<Lifecycle> {
+    let product = product(package, source)?;
+    let cycles = match data {
+        Some(path) => {
+            let parsed: Value = serde_json::from_str(&fs::read_to_string(path).ok()?).ok()?;
+            parsed.get(product)?.clone()
+        }
+        None => serde_json::from_str(&fetch(product, use_cache)?).ok()?,
+    };
+    status(&cycles, version, &today())
+}
+
+fn fetch(product: &str, use_cache: bool) -> Option<String> {
+    if use_cache && let Some(cached) = cache::get("eol", product) {
+        return Some(cached);
+    }
+    let url = format!("https://endoflife.date/api/{product}.json");
+    let output = Command::new("curl").args(["-sf", "-m", "10", &url]).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let body = String::from_utf8_lossy(&output.stdout).into_owned();
+    if use_cache {
+        cache::set("eol", product, &body);
+    }
+    Some(body)
+}
+
+/// Find the cycle `version` belongs to in an endoflife.date cycle list. Dates
+/// (`YYYY-MM-DD`) compare as strings; `eol`, `support` and `lts` may each be a
+/// date or a boolean
+fn status(cycles: &Value, version: &str, today: &str) -> Option<Lifecycle> {
+    let version = version.trim_start_matches('v');
+    let numeric = version.split(|c: char| !c.is_ascii_digit() && c != '.').next()?;
+    let cycle = cycles.as_array()?.iter().find(|c| {
+        cycle_name(c)
+            .is_some_and(|name| numeric == name || numeric.starts_with(&format!("{name}.")))
+    })?;
+
+    let date = |key| cycle.get(key).and_then(Value::as_str).map(String::from);
+    let reached = |key| match cycle.get(key) {
+        Some(Value::Bool(b)) => *b,
+        Some(Value::String(d)) => d.as_str() <= today,
+        _ => false,
+    };
+    // A boolean `support` says whether the cycle is still actively supported; cycles
+    // without a separate active support window are supported until end of life
+    let (support_end, support_ended) = match cycle.get("support") {
+        Some(Value::Bool(supported)) => (None, !supported),
+        Some(_) => (date("support"), reached("support")),
+        None => (date("eol"), reached("eol")),
+    };
+    Some(Lifecycle {
+        cycle: cycle_name(cycle)?,
+        lts: reached("lts"),
+        support_end,
+        support_ended,
+        eol: date("eol"),
+        end_of_life: reached("eol"),
+    })
+}
+
+fn cycle_name(cycle: &Value) -> Option<String> {
+    match cycle.get("cycle")? {
+        Value::String(s) => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        _ => None,
+    }
+}
+
+/// Today's UTC date as `YYYY-MM-DD`
+fn today() -> String {
+    let secs = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs());
+    civil_date(secs / 86_400)
+}
+
+/// Days since 1970-01-01 to a calendar date (Howard Hinnant's `civil_from_days`)
+fn civil_date(days: u64) -> String {
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let doe = z % 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + u64::from(month <= 2);
+    format!("{year:04}-{month:02}-{day:02}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NODE: &str = r#"[
+        {"cycle":"22","releaseDate":"2024-04-24","lts":"2024-10-29","support":"2025-10-21","eol":"2027-04-30","latest":"22.12.0"},
+        {"cycle":"21","releaseDate":"2023-10-17","lts":false,"support":"2024-04-01","eol":"2024-06-01","latest":"21.7.3"},
+        {"cycle":"20","releaseDate":"2023-04-18","lts":"2023-10-24","support":"2024-10-22","eol":"2026-04-30","latest":"20.18.1"},
+        {"cycle":"18","releaseDate":"2022-04-19","lts":"2022-10-25","support":"2023-10-18","eol":"2025-04-30","latest":"18.20.5"}
+    ]"#;
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_status() {
+        let cycles: Value = serde_json::from_str(NODE).unwrap();
+        assert_eq!(
+            status(&cycles, "18.20.5", "2025-06-01"),
+            Some(Lifecycle {
+                cycle: "18".into(),
+                lts: true,
+                support_end: Some("2023-10-18".into()),
+                support_ended: true,
+                eol: Some("2025-04-30".into()),
+                end_of_life: true,
+            })
+        );
+        let node20 = status(&cycles, "v20.11.0", "2025-06-01").unwrap();
+        assert!(node20.lts && node20.support_ended && !node20.end_of_life);
+        // Not LTS until its LTS date
+        assert!(!status(&cycles, "22.1.0", "2024-06-01").unwrap().lts);
+        assert_eq!(status(&cycles, "16.20.2", "2025-06-01"), None);
+        assert_eq!(status(&cycles, "2", "2025-06-01"), None);
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn test_status_boolean_fields() {
+        let cycles: Value = serde_json::from_str(
+            r#"[{"cycle":"3.12","support":"2025-04-02","eol":"2028-10-31"},
+                {"cycle":"2.7","support":false,"eol":true},
+                {"cycle":16,"eol":"2028-11-09"}]"#,
+        )
+        .unwrap();
+        let py27 = status(&cycles, "2.7.18", "2025-01-01").unwrap();
+        assert!(py27.end_of_life && py27.support_ended);
+        assert_eq!(py27.eol, None);
+        let postgres = status(&cycles, "16.1", "2025-01-01").unwrap();
+        assert_eq!(postgres.support_end.as_deref(), Some("2028-11-09"));
+        assert!(!postgres.support_ended);
+        assert_eq!(status(&cycles, "3.12.1", "2025-01-01").unwrap().cycle, "3.12");
+        assert_eq!(status(&cycles, "16.1-alpine", "2025-01-01").unwrap().cycle, "16");
+    }
+
+    #[test]
+    fn test_product() {
+        assert_eq!(product("node", "path"), Some("nodejs"));
+        assert_eq!(product("node@lts", "node"), Some("nodejs"));
+        assert_eq!(product("docker.io/library/postgres", "docker"), Some("postgresql"));
+        assert_eq!(product("laravel/framework", "composer"), Some("laravel"));
+        assert_eq!(product("@angular/core", "npm"), Some("angular"));
+        assert_eq!(product("Django", "pypi"), Some("django"));
+        // Clients named after the server they talk to
+        assert_eq!(product("redis", "npm"), None);
+        assert_eq!(product("acme/redis", "composer"), None);
+        assert_eq!(product("express", "npm"), None);
+    }
+
+    #[test]
+    fn test_civil_date() {
+        assert_eq!(civil_date(0), "1970-01-01");
+        assert_eq!(civil_date(19_782), "2024-02-29");
+        assert_eq!(civil_date(20_000), "2024-10-04");
+    }
+}
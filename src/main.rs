@@ -1,7 +1,8 @@
 use clap::Parser;
 use latest::cache;
 use latest::config::Config;
-use latest::project;
+use latest::lifecycle::{self, Lifecycle};
+use latest::project::{self, Dependency};
 use latest::sources::{self, Source, source_by_name};
 use rayon::prelude::*;
 
@@ -74,6 +75,18 @@ struct Cli {
     /// Only use local sources (no network requests)
     #[arg(long)]
     offline: bool,
+
+    /// Include transitive dependencies when scanning lockfiles
+    #[arg(long)]
+    transitive: bool,
+
+    /// Compare Node.js against the latest LTS release instead of Current
+    #[arg(long)]
+    lts: bool,
+
+    /// Include prereleases from GitHub and GitLab releases
+    #[arg(long)]
+    prerelease: bool,
 }
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -122,6 +135,18 @@ struct PackageResult {
     /// Other sources where the package was found (for clash warnings)
     #[serde(skip_serializing_if = "Vec::is_empty")]
     also_found_in: Vec<String>,
+    /// Source-specific remarks, e.g. a newer major version under another module path
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    notes: Vec<String>,
+    /// Why an outdated package needs updating when it isn't a newer version
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reason: Option<String>,
+    /// File and line the dependency was read from, when a project declares it in several places
+    #[serde(skip_serializing_if = "Option::is_none")]
+    location: Option<String>,
+    /// Support window of the installed or pinned version's release cycle
+    #[serde(skip_serializing_if = "Option::is_none")]
+    lifecycle: Option<Lifecycle>,
 }
 
 impl PackageResult {
@@ -134,6 +159,10 @@ impl PackageResult {
             available: Vec::new(),
             install_commands: Vec::new(),
             also_found_in: Vec::new(),
+            notes: Vec::new(),
+            reason: None,
+            location: None,
+            lifecycle: None,
         }
     }
 
@@ -146,6 +175,10 @@ impl PackageResult {
             available: Vec::new(),
             install_commands: Vec::new(),
             also_found_in,
+            notes: Vec::new(),
+            reason: None,
+            location: None,
+            lifecycle: None,
         }
     }
 
@@ -163,6 +196,10 @@ impl PackageResult {
             available: Vec::new(),
             install_commands: Vec::new(),
             also_found_in,
+            notes: Vec::new(),
+            reason: None,
+            location: None,
+            lifecycle: None,
         }
     }
 
@@ -176,6 +213,10 @@ impl PackageResult {
             available,
             install_commands,
             also_found_in: Vec::new(),
+            notes: Vec::new(),
+            reason: None,
+            location: None,
+            lifecycle: None,
         }
     }
 
@@ -188,6 +229,10 @@ impl PackageResult {
             available,
             install_commands: Vec::new(),
             also_found_in: Vec::new(),
+            notes: Vec::new(),
+            reason: None,
+            location: None,
+            lifecycle: None,
         }
     }
 }
@@ -198,24 +243,59 @@ impl PackageResult {
 
 use latest::{is_newer, parse_package_arg};
 
-/// Query a source with optional caching (only for non-local sources)
+/// Query a source with optional caching (only for non-local sources).
+/// A `registry` redirects the query to an alternate index for that source.
 #[allow(clippy::collapsible_if)] // Let chains require nightly rustfmt
-fn query_source(source: &dyn Source, package: &str, use_cache: bool) -> Option<String> {
+fn query_source(
+    source: &dyn Source,
+    package: &str,
+    registry: Option<&str>,
+    use_cache: bool,
+) -> Option<String> {
+    let fetch = || {
+        registry.map_or_else(
+            || source.get_version(package),
+            |registry| source.get_version_from(package, registry),
+        )
+    };
     if source.is_local() {
-        return source.get_version(package);
+        return fetch();
     }
+    let key = registry.map_or_else(|| package.to_string(), |r| format!("{r} {package}"));
     if use_cache {
-        if let Some(cached) = cache::get(source.name(), package) {
+        if let Some(cached) = cache::get(source.name(), &key) {
             return Some(cached);
         }
     }
-    let version = source.get_version(package)?;
+    let version = fetch()?;
     if use_cache {
-        cache::set(source.name(), package, &version);
+        cache::set(source.name(), &key, &version);
     }
     Some(version)
 }
 
+/// Every release of a package, cached like `query_source` (one version per line)
+fn query_versions(
+    source: &dyn Source,
+    package: &str,
+    registry: Option<&str>,
+    use_cache: bool,
+) -> Option<Vec<String>> {
+    let cache_name = format!("{}-versions", source.name());
+    let key = registry.map_or_else(|| package.to_string(), |r| format!("{r} {package}"));
+    if use_cache && let Some(cached) = cache::get(&cache_name, &key) {
+        return Some(cached.lines().map(String::from).collect());
+    }
+    let versions = registry.map_or_else(
+        || source.get_versions(package),
+        |registry| source.get_versions_from(package, registry),
+    )?;
+    if use_cache {
+        cache::set(&cache_name, &key, &versions.join("\n"));
+    }
+    Some(versions)
+}
+
 #[derive(Clone, Copy)]
 enum LookupMode {
     All,
@@ -234,7 +314,7 @@ fn lookup(
             let available: Vec<_> = sources
                 .par_iter()
                 .filter_map(|s| {
-                    query_source(s.as_ref(), package, use_cache)
+                    query_source(s.as_ref(), package, None, use_cache)
                         .map(|v| VersionInfo::new(&v, s.as_ref()))
                 })
                 .collect();
@@ -243,29 +323,52 @@ fn lookup(
         LookupMode::Explicit => sources
             .par_iter()
             .find_map_any(|s| {
-                query_source(s.as_ref(), package, use_cache)
-                    .map(|v| VersionInfo::new(&v, s.as_ref()))
+                query_source(s.as_ref(), package, None, use_cache).map(|v| (v, s.as_ref()))
             })
             .map_or_else(
                 || PackageResult::not_found(package),
-                |info| PackageResult::up_to_date(package, info, Vec::new()),
+                |(v, s)| {
+                    let latest = VersionInfo::new(&v, s);
+                    // An image registry is the registry side for pulled images, and a
+                    // releases source for the tool's command on $PATH
+                    let installed = if s.name() == "docker" {
+                        let images = sources::ImagesSource;
+                        images
+                            .get_version(package)
+                            .map(|version| VersionInfo::new(&version, &images))
+                    } else {
+                        let path = sources::PathSource;
+                        s.command(package)
+                            .and_then(|command| path.get_version(&command))
+                            .filter(|version| version != "installed")
+                            .map(|version| VersionInfo::new(&version, &path))
+                    };
+                    let result = match installed {
+                        Some(i) if s.is_newer(&i.version, &v) => {
+                            return PackageResult::outdated(package, i, latest, Vec::new());
+                        }
+                        Some(i) => PackageResult::up_to_date(package, i, Vec::new()),
+                        None => PackageResult::up_to_date(package, latest, Vec::new()),
+                    };
+                    PackageResult { notes: s.notes(package, use_cache), ..result }
+                },
             ),
         LookupMode::Default => lookup_default(package, sources, use_cache),
     }
 }
 
 fn lookup_default(package: &str, sources: &[Box<dyn Source>], use_cache: bool) -> PackageResult {
-    // Find installed version from local sources
+    // Find installed version from local sources, the first in precedence order
     let installed = sources
         .par_iter()
         .filter(|s| s.is_local())
-        .find_map_any(|s| s.get_version(package).map(|v| (v, s.as_ref())));
+        .find_map_first(|s| s.get_version(package).map(|v| (v, s.as_ref())));
 
     // Find versions from registries
     let registry_versions: Vec<_> = sources
         .par_iter()
         .filter(|s| !s.is_local())
-        .filter_map(|s| query_source(s.as_ref(), package, use_cache).map(|v| (v, s.as_ref())))
+        .filter_map(|s| query_source(s.as_ref(), package, None, use_cache).map(|v| (v, s.as_ref())))
         .collect();
 
     match installed {
@@ -310,6 +413,88 @@ fn lookup_default(package: &str, sources: &[Box<dyn Source>], use_cache: bool) -
     }
 }
 
+/// Look up a dependency read from a project file. A pinned or locked version
+/// stands in for the installed one and is compared against the registry.
+fn lookup_dependency(
+    dep: &Dependency,
+    sources: &[Box<dyn Source>],
+    use_cache: bool,
+) -> PackageResult {
+    let package = dep.name.as_str();
+    let Some((version, source)) = sources.par_iter().find_map_any(|s| {
+        query_source(s.as_ref(), package, dep.registry.as_deref(), use_cache)
+            .map(|v| (v, s.as_ref()))
+    }) else {
+        return PackageResult::not_found(package);
+    };
+    let latest = VersionInfo::new(&version, source);
+
+    let mut notes = Vec::new();
+    let result = match &dep.version {
+        Some(pinned) if source.is_newer(pinned, &version) => {
+            let pinned_info = VersionInfo { version: sanitize_output(pinned), ..latest.clone() };
+            let Some(req) = dep
+                .constraint
+                .as_deref()
+                .filter(|req| source.satisfies(&version, req) == Some(false))
+            else {
+                return PackageResult::outdated(package, pinned_info, latest, Vec::new());
+            };
+            // A release the declared requirement excludes needs a manual bump, not an
+            // update; the newest release it does allow may still be one
+            notes.push(format!("{version} available outside requirement {req}"));
+            let allowed = query_versions(source, package, dep.registry.as_deref(), use_cache)
+                .and_then(|versions| {
+                    let allowed = versions
+                        .iter()
+                        .map(String::as_str)
+                        .filter(|v| source.satisfies(v, req) == Some(true));
+                    sources::highest_version(allowed).map(String::from)
+                })
+                .filter(|allowed| source.is_newer(pinned, allowed));
+            match allowed {
+                Some(allowed) => {
+                    let result = PackageResult::outdated(
+                        package,
+                        pinned_info,
+                        VersionInfo::new(&allowed, source),
+                        Vec::new(),
+                    );
+                    return PackageResult { notes, ..result };
+                }
+                None => PackageResult::up_to_date(package, pinned_info, Vec::new()),
+            }
+        }
+        Some(pinned) => {
+            let pinned_info = VersionInfo { version: sanitize_output(pinned), ..latest };
+            // Same tag, different content: the installed or pinned copy is stale
+            if source.rebuilt(package, pinned, dep.digest.as_deref()) {
+                let result =
+                    PackageResult::outdated(package, pinned_info.clone(), pinned_info, Vec::new());
+                return PackageResult {
+                    reason: Some("tag rebuilt upstream".to_string()),
+                    ..result
+                };
+            }
+            PackageResult::up_to_date(package, pinned_info, Vec::new())
+        }
+        None => {
+            // Nothing locked to compare, but a requirement that excludes the latest
+            // release needs editing before anything can pick it up
+            if let Some(req) = dep
+                .constraint
+                .as_deref()
+                .filter(|req| source.satisfies(&version, req) == Some(false))
+            {
+                notes.push(format!("{version} available outside requirement {req}"));
+            }
+            PackageResult::up_to_date(package, latest, Vec::new())
+        }
+    };
+    notes.extend(source.notes(package, use_cache));
+    PackageResult { notes, ..result }
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Install commands
 // ─────────────────────────────────────────────────────────────────────────────
@@ -321,6 +506,7 @@ fn get_install_commands(package: &str, available: &[VersionInfo]) -> Vec<String>
         Path::new("package.json").exists(),
         Path::new("Cargo.toml").exists(),
         Path::new("go.mod").exists(),
+        Path::new("composer.json").exists(),
     );
 
     available
@@ -337,12 +523,33 @@ fn get_install_commands(package: &str, available: &[VersionInfo]) -> Vec<String>
                 }
                 "go" => format!("go {} {}", if context.3 { "get" } else { "install" }, package),
                 "pub" => format!("dart pub add {package}"),
+                "nuget" => format!("dotnet add package {package}"),
+                "docker" => {
+                    let image = package.split_once("@*").map_or(package, |(image, _)| image);
+                    format!("docker pull {image}:{}", v.version)
+                }
+                "composer" => {
+                    format!("composer {}require {package}", if context.4 { "" } else { "global " })
+                }
+                "gem" => {
+                    bundler_command(package).unwrap_or_else(|| format!("gem install {package}"))
+                }
                 _ => return None,
             })
         })
         .collect()
 }
 
+/// `bundle update` for gems already in Gemfile.lock, `bundle add` for new ones
+fn bundler_command(package: &str) -> Option<String> {
+    use std::fs;
+    fs::metadata("Gemfile").ok()?;
+    let locked = fs::read_to_string("Gemfile.lock").is_ok_and(|lock| {
+        lock.lines().any(|l| l.trim_start().starts_with(&format!("{package} (")))
+    });
+    Some(format!("bundle {} {package}", if locked { "update" } else { "add" }))
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Output formatting
 // ─────────────────────────────────────────────────────────────────────────────
@@ -351,7 +558,11 @@ fn get_install_commands(package: &str, available: &[VersionInfo]) -> Vec<String>
 /// Uses unwrap on installed/latest because status guarantees their presence.
 #[allow(clippy::unwrap_used)]
 fn format_result(r: &PackageResult, show_name: bool) -> String {
-    let pkg_prefix = if show_name { format!("{}: ", r.package) } else { String::new() };
+    let pkg_prefix = match (&r.location, show_name) {
+        (Some(location), true) => format!("{} ({}): ", r.package, sanitize_output(location)),
+        (None, true) => format!("{}: ", r.package),
+        (_, false) => String::new(),
+    };
     match r.status {
         Status::UpToDate => {
             let info = r.installed.as_ref().unwrap();
@@ -370,8 +581,9 @@ fn format_result(r: &PackageResult, show_name: bool) -> String {
             } else {
                 ""
             };
+            let reason = r.reason.as_ref().map(|r| format!(" ({r})")).unwrap_or_default();
             format!(
-                "{pkg_prefix}{}: {}{} → {} available",
+                "{pkg_prefix}{}: {}{} → {} available{reason}",
                 installed.source, installed.version, installed_marker, latest
             )
         }
@@ -440,11 +652,54 @@ fn output_results(cli: &Cli, results: &[PackageResult]) {
                 if !r.also_found_in.is_empty() {
                     eprintln!("⚠ Also found in: {}", r.also_found_in.join(", "));
                 }
+                for note in &r.notes {
+                    eprintln!("⚠ {}", sanitize_output(note));
+                }
+                if let Some(warning) = r.lifecycle.as_ref().and_then(lifecycle_warning) {
+                    eprintln!("⚠ {}", sanitize_output(&warning));
+                }
             }
         }
     }
 }
 
+/// A release cycle past end of life, or past active support, is worth flagging
+/// even when the version is the newest in its cycle
+fn lifecycle_warning(l: &Lifecycle) -> Option<String> {
+    let since =
+        |date: &Option<String>| date.as_ref().map(|d| format!(" since {d}")).unwrap_or_default();
+    if l.end_of_life {
+        Some(format!("{} is end of life{}", l.cycle, since(&l.eol)))
+    } else if l.support_ended {
+        let until =
+            l.eol.as_ref().map(|d| format!(", security fixes until {d}")).unwrap_or_default();
+        Some(format!("{} is out of active support{}{until}", l.cycle, since(&l.support_end)))
+    } else {
+        None
+    }
+}
+
+/// Node (a pin, or the installed one) is compared against the LTS line rather than
+/// Current, and releases sources take prereleases when asked. `source` is the
+/// package's own (prefix or scanned) source, which wins over `global` (--source)
+fn release_channel(
+    source: Option<String>,
+    dep: Dependency,
+    global: Option<&str>,
+    lts: bool,
+    prerelease: bool,
+) -> (Option<String>, Dependency) {
+    match source.as_deref().or(global) {
+        None | Some("node") if lts && matches!(dep.name.as_str(), "node" | "nodejs") => {
+            (source, Dependency { name: "node@lts".to_string(), ..dep })
+        }
+        Some("github" | "gitlab") if prerelease && !dep.name.ends_with("@pre") => {
+            (source, Dependency { name: format!("{}@pre", dep.name), ..dep })
+        }
+        _ => (source, dep),
+    }
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Main
 // ─────────────────────────────────────────────────────────────────────────────
@@ -453,23 +708,40 @@ fn main() {
     let cli = Cli::parse();
     let config = Config::load();
 
-    let (packages, source_override): (Vec<(Option<String>, String)>, Option<&str>) =
-        if cli.packages.is_empty() {
-            if let Some(p) = project::scan() {
-                if !cli.json && !cli.quiet {
-                    eprintln!("Scanning {}...", p.file);
-                }
-                (p.packages.into_iter().map(|s| (None, s)).collect(), Some(p.source))
-            } else {
-                eprintln!("No project file found. Usage: latest <package> [...]");
-                std::process::exit(1);
+    let packages: Vec<(Option<String>, Dependency)> = if cli.packages.is_empty() {
+        let projects = project::scan();
+        if projects.is_empty() {
+            eprintln!("No project file found. Usage: latest <package> [...]");
+            std::process::exit(1);
+        }
+        let mut packages = Vec::new();
+        for p in projects {
+            if !cli.json && !cli.quiet {
+                eprintln!("Scanning {}...", p.file);
             }
-        } else {
-            (cli.packages.iter().map(|s| parse_package_arg(s)).collect(), None)
-        };
+            // A dependency's own source wins, then --source, then the project's
+            let project_source = cli.source.is_none().then_some(p.source);
+            packages.extend(
+                p.packages
+                    .into_iter()
+                    .filter(|d| cli.transitive || !d.transitive)
+                    .map(|d| (d.source.or(project_source).map(String::from), d)),
+            );
+        }
+        packages
+    } else {
+        let parsed = cli.packages.iter().map(|s| parse_package_arg(s));
+        parsed.map(|(source, pkg)| (source, Dependency::new(pkg))).collect()
+    };
+
+    let packages: Vec<(Option<String>, Dependency)> = packages
+        .into_iter()
+        .map(|(source, dep)| {
+            release_channel(source, dep, cli.source.as_deref(), cli.lts, cli.prerelease)
+        })
+        .collect();
 
-    // Global source override from --source flag or project detection
-    let global_source = cli.source.as_deref().or(source_override);
+    let global_source = cli.source.as_deref();
 
     // Validate global source if specified via --source
     if let Some(name) = cli.source.as_deref()
@@ -483,13 +755,14 @@ fn main() {
 
     let results: Vec<_> = packages
         .par_iter()
-        .map(|(prefix_source, pkg)| {
+        .map(|(prefix_source, dep)| {
+            let pkg = dep.name.as_str();
             // Prefix source takes priority over global source
             let source_name = prefix_source.as_deref().or(global_source);
 
             let sources_to_use: Vec<Box<dyn Source>> = match source_name {
                 Some(name) => source_by_name(name).map_or_else(Vec::new, |s| vec![s]),
-                None => config.precedence.iter().map(sources::SourceType::create).collect(),
+                None => sources::lookup_sources(&config.precedence, pkg),
             };
 
             // Filter to local-only sources when offline mode is enabled
@@ -510,7 +783,23 @@ fn main() {
                 _ => LookupMode::Default,
             };
 
-            lookup(pkg, &sources_to_use, mode, use_cache)
+            let pinned =
+                dep.version.is_some() || dep.registry.is_some() || dep.constraint.is_some();
+            let result = if pinned && !matches!(mode, LookupMode::All) {
+                lookup_dependency(dep, &sources_to_use, use_cache)
+            } else {
+                lookup(pkg, &sources_to_use, mode, use_cache)
+            };
+            // Support windows come from the network unless a local copy is configured
+            let lifecycle = result
+                .installed
+                .as_ref()
+                .filter(|_| !cli.offline || config.eol_data.is_some())
+                .and_then(|i| {
+                    let data = config.eol_data.as_deref();
+                    lifecycle::lookup(pkg, &i.source, &i.version, data, use_cache)
+                });
+            PackageResult { location: dep.location.clone(), lifecycle, ..result }
         })
         .collect();
 
@@ -519,7 +808,9 @@ fn main() {
     let code =
         if results.iter().any(|r| matches!(r.status, Status::NotFound | Status::NotInstalled)) {
             1
-        } else if results.iter().any(|r| r.status == Status::Outdated) {
+        } else if results.iter().any(|r| {
+            r.status == Status::Outdated || r.lifecycle.as_ref().is_some_and(|l| l.end_of_life)
+        }) {
             2
         } else {
             0
@@ -550,6 +841,8 @@ mod tests {
         packages: Vec<(&'static str, &'static str)>,
         local: bool,
         ecosystem: Ecosystem,
+        /// Digest the registry currently serves for every tag
+        upstream_digest: Option<&'static str>,
     }
 
     impl Source for MockSource {
@@ -565,6 +858,21 @@ mod tests {
         fn get_version(&self, pkg: &str) -> Option<String> {
             self.packages.iter().find(|(n, _)| *n == pkg).map(|(_, v)| v.to_string())
         }
+        fn get_versions(&self, pkg: &str) -> Option<Vec<String>> {
+            Some(
+                self.packages
+                    .iter()
+                    .filter(|(n, _)| *n == pkg)
+                    .map(|(_, v)| v.to_string())
+                    .collect(),
+            )
+        }
+        fn satisfies(&self, version: &str, requirement: &str) -> Option<bool> {
+            sources::matches_requirement(version, requirement)
+        }
+        fn rebuilt(&self, _package: &str, _version: &str, digest: Option<&str>) -> bool {
+            digest.zip(self.upstream_digest).is_some_and(|(local, upstream)| local != upstream)
+        }
     }
 
     fn mock(
@@ -573,7 +881,7 @@ mod tests {
         local: bool,
         ecosystem: Ecosystem,
     ) -> Box<dyn Source> {
-        Box::new(MockSource { name, packages, local, ecosystem })
+        Box::new(MockSource { name, packages, local, ecosystem, upstream_digest: None })
     }
 
     #[test]
@@ -632,6 +940,58 @@ mod tests {
         assert_eq!(lookup("node", &sources, LookupMode::All, false).available.len(), 2);
     }
 
+    #[test]
+    fn test_lifecycle_warning() {
+        let cycle = |support_ended, end_of_life| Lifecycle {
+            cycle: "18".into(),
+            lts: true,
+            support_end: Some("2023-10-18".into()),
+            support_ended,
+            eol: Some("2025-04-30".into()),
+            end_of_life,
+        };
+        assert_eq!(
+            lifecycle_warning(&cycle(true, true)).as_deref(),
+            Some("18 is end of life since 2025-04-30")
+        );
+        assert_eq!(
+            lifecycle_warning(&cycle(true, false)).as_deref(),
+            Some("18 is out of active support since 2023-10-18, security fixes until 2025-04-30")
+        );
+        assert_eq!(lifecycle_warning(&cycle(false, false)), None);
+    }
+
+    #[test]
+    fn test_release_channel() {
+        let channel = |package: &str, source: Option<&str>, global, lts, prerelease| {
+            let source = source.map(String::from);
+            let (source, dep) =
+                release_channel(source, Dependency::new(package), global, lts, prerelease);
+            (source, dep.name)
+        };
+        assert_eq!(channel("node", None, None, true, false), (None, "node@lts".into()));
+        assert_eq!(
+            channel("nodejs", Some("node"), None, true, false),
+            (Some("node".into()), "node@lts".into())
+        );
+        assert_eq!(channel("node", None, Some("node"), true, false), (None, "node@lts".into()));
+        assert_eq!(channel("node", None, Some("brew"), true, false), (None, "node".into()));
+        let fzf = |s: &str| (None, s.to_string());
+        assert_eq!(
+            channel("junegunn/fzf", None, Some("github"), false, true),
+            fzf("junegunn/fzf@pre")
+        );
+        assert_eq!(
+            channel("gitlab-org/cli@pre", Some("gitlab"), None, false, true),
+            (Some("gitlab".into()), "gitlab-org/cli@pre".into())
+        );
+        assert_eq!(channel("junegunn/fzf", None, None, false, true), fzf("junegunn/fzf"));
+        assert_eq!(
+            channel("junegunn/fzf", None, Some("github"), false, false),
+            fzf("junegunn/fzf")
+        );
+    }
+
     #[test]
     fn test_parse_package_arg_with_prefix() {
         let (source, pkg) = parse_package_arg("npm:express");
@@ -671,6 +1031,106 @@ mod tests {
         assert!(!r.also_found_in.contains(&"brew".to_string()));
     }
 
+    #[test]
+    fn test_lookup_dependency_pinned_outdated() {
+        let sources = vec![mock("pypi", vec![("requests", "2.32.3")], false, Ecosystem::Python)];
+        let dep = Dependency { version: Some("2.28.0".to_string()), ..Dependency::new("requests") };
+        let r = lookup_dependency(&dep, &sources, false);
+        assert_eq!(r.status, Status::Outdated);
+        assert_eq!(r.installed.map(|i| i.version).as_deref(), Some("2.28.0"));
+        assert_eq!(r.latest.map(|i| i.version).as_deref(), Some("2.32.3"));
+    }
+
+    #[test]
+    fn test_lookup_dependency_pinned_current() {
+        let sources = vec![mock("pypi", vec![("requests", "2.32.3")], false, Ecosystem::Python)];
+        let dep = Dependency { version: Some("2.32.3".to_string()), ..Dependency::new("requests") };
+        assert_eq!(lookup_dependency(&dep, &sources, false).status, Status::UpToDate);
+    }
+
+    #[test]
+    fn test_lookup_dependency_outside_requirement() {
+        let sources = vec![mock("hex", vec![("phoenix", "2.0.0")], false, Ecosystem::Beam)];
+        let dep = Dependency {
+            version: Some("1.7.14".to_string()),
+            constraint: Some("~> 1.7".to_string()),
+            ..Dependency::new("phoenix")
+        };
+        let r = lookup_dependency(&dep, &sources, false);
+        assert_eq!(r.status, Status::UpToDate);
+        assert_eq!(r.notes, vec!["2.0.0 available outside requirement ~> 1.7".to_string()]);
+
+        let dep = Dependency { constraint: Some("~> 1.0 or ~> 2.0".to_string()), ..dep };
+        assert_eq!(lookup_dependency(&dep, &sources, false).status, Status::Outdated);
+    }
+
+    #[test]
+    fn test_lookup_dependency_newest_within_requirement() {
+        // The latest release is the first listed
+        let releases = vec![("rails", "8.0.0"), ("rails", "7.1.4"), ("rails", "7.1.3")];
+        let sources = vec![mock("gem", releases, false, Ecosystem::Ruby)];
+        let dep = Dependency {
+            version: Some("7.1.3".to_string()),
+            constraint: Some("~> 7.1".to_string()),
+            ..Dependency::new("rails")
+        };
+        let r = lookup_dependency(&dep, &sources, false);
+        assert_eq!(r.status, Status::Outdated);
+        assert_eq!(r.latest.map(|l| l.version).as_deref(), Some("7.1.4"));
+        assert_eq!(r.notes, vec!["8.0.0 available outside requirement ~> 7.1".to_string()]);
+    }
+
+    #[test]
+    fn test_lookup_dependency_unlocked_requirement() {
+        let sources = vec![mock("dart", vec![("dart", "4.0.0")], false, Ecosystem::System)];
+        let dep = Dependency {
+            constraint: Some(">=3.0.0 <4.0.0".to_string()),
+            ..Dependency::new("dart")
+        };
+        let r = lookup_dependency(&dep, &sources, false);
+        assert_eq!(r.status, Status::UpToDate);
+        assert_eq!(r.notes, vec!["4.0.0 available outside requirement >=3.0.0 <4.0.0".to_string()]);
+
+        let dep = Dependency { constraint: Some("^4.0.0".to_string()), ..dep };
+        let r = lookup_dependency(&dep, &sources, false);
+        assert_eq!(r.status, Status::UpToDate);
+        assert!(r.notes.is_empty());
+    }
+
+    #[test]
+    fn test_lookup_dependency_rebuilt_tag() {
+        let sources: Vec<Box<dyn Source>> = vec![Box::new(MockSource {
+            name: "docker",
+            packages: vec![("python@*-slim", "3.12-slim")],
+            local: false,
+            ecosystem: Ecosystem::Container,
+            upstream_digest: Some("sha256:new"),
+        })];
+        let dep = Dependency {
+            version: Some("3.12-slim".to_string()),
+            digest: Some("sha256:old".to_string()),
+            ..Dependency::new("python@*-slim")
+        };
+        let r = lookup_dependency(&dep, &sources, false);
+        assert_eq!(r.status, Status::Outdated);
+        assert_eq!(r.reason.as_deref(), Some("tag rebuilt upstream"));
+        assert!(format_result(&r, false).ends_with("3.12-slim available (tag rebuilt upstream)"));
+
+        let dep = Dependency { digest: Some("sha256:new".to_string()), ..dep };
+        assert_eq!(lookup_dependency(&dep, &sources, false).status, Status::UpToDate);
+    }
+
+    #[test]
+    fn test_lookup_dependency_registry_unsupported() {
+        // Sources without alternate registry support must not fall back to the public one
+        let sources = vec![mock("pypi", vec![("internal", "1.0.0")], false, Ecosystem::Python)];
+        let dep = Dependency {
+            registry: Some("https://pypi.example.com/simple".to_string()),
+            ..Dependency::new("internal")
+        };
+        assert_eq!(lookup_dependency(&dep, &sources, false).status, Status::NotFound);
+    }
+
     // ─────────────────────────────────────────────────────────────────────────
     // Sanitization tests (TDD: tests written first)
     // ─────────────────────────────────────────────────────────────────────────
@@ -0,0 +1,161 @@
+//! GitHub Actions workflow scanning: `uses:` references in workflows and
+//! composite actions
+
+use super::{Dependency, ProjectInfo};
+use regex::Regex;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+
+/// `uses: owner/repo@ref`, optionally quoted, with an optional trailing comment
+static USES: LazyLock<Regex> = LazyLock::new(|| {
+    #[allow(clippy::unwrap_used)]
+    Regex::new(r#"^\s*(?:-\s+)?uses:\s*["']?([^"'\s#]+)["']?\s*(?:#\s*(.*))?$"#).unwrap()
+});
+
+static VERSION: LazyLock<Regex> = LazyLock::new(|| {
+    #[allow(clippy::unwrap_used)]
+    Regex::new(r"^v?([0-9]+(?:\.[0-9]+)*)$").unwrap()
+});
+
+pub fn scan() -> Option<ProjectInfo> {
+    let mut files = Vec::new();
+    yaml_files(Path::new(".github/workflows"), &mut files);
+    // Composite actions in this repo, whose steps can `uses:` other actions
+    if let Ok(entries) = fs::read_dir(".github/actions") {
+        for entry in entries.flatten() {
+            files.extend(action_file(&entry.path()));
+        }
+    }
+    files.extend(action_file(Path::new(".")));
+    files.sort();
+
+    let packages: Vec<Dependency> = files
+        .iter()
+        .filter_map(|path| {
+            let content = fs::read_to_string(path).ok()?;
+            let display = path.strip_prefix(".").unwrap_or(path).display().to_string();
+            Some(parse_workflow(&content, display.trim_start_matches('/')))
+        })
+        .flatten()
+        .collect();
+    if packages.is_empty() {
+        return None;
+    }
+
+    Some(ProjectInfo { file: ".github/workflows", source: "actions", packages })
+}
+
+fn yaml_files(dir: &Path, files: &mut Vec<PathBuf>) {
+    let Ok(entries) = fs::read_dir(dir) else { return };
+    files.extend(entries.flatten().map(|e| e.path()).filter(|path| {
+        path.extension()
+            .is_some_and(|e| e.eq_ignore_ascii_case("yml") || e.eq_ignore_ascii_case("yaml"))
+    }));
+}
+
+fn action_file(dir: &Path) -> Option<PathBuf> {
+    ["action.yml", "action.yaml"].iter().map(|name| dir.join(name)).find(|path| path.is_file())
+}
+
+/// Every remote action or reusable workflow referenced in `content`, located at
+/// its line. Local (`./`) and `docker://` references have no tags to compare
+fn parse_workflow(content: &str, file: &str) -> Vec<Dependency> {
+    content
+        .lines()
+        .enumerate()
+        .filter_map(|(line, text)| {
+            let caps = USES.captures(text)?;
+            let reference = caps.get(1)?.as_str();
+            if reference.starts_with("./")
+                || reference.starts_with("../")
+                || reference.contains("://")
+            {
+                return None;
+            }
+            let (action, git_ref) = reference.rsplit_once('@')?;
+            let comment = caps.get(2).map(|c| c.as_str().trim());
+            Some(Dependency {
+                version: pinned_version(git_ref, comment),
+                location: Some(format!("{file}:{}", line + 1)),
+                ..Dependency::new(action)
+            })
+        })
+        .collect()
+}
+
+/// The version a ref pins: a tag (`v4`, `v4.1.1`), or for a full commit SHA the
+/// tag in its comment (`@b4ffde6... # v4.1.1`). Branches pin no version
+fn pinned_version(git_ref: &str, comment: Option<&str>) -> Option<String> {
+    let is_sha = git_ref.len() == 40 && git_ref.chars().all(|c| c.is_ascii_hexdigit());
+    let tag = if is_sha {
+        // Comments are often `# v4.1.1` but sometimes `# pin@v4.1.1` or `# tag=v4.1.1`
+        comment?.split_whitespace().next()?.rsplit(['@', '=']).next()?
+    } else {
+        git_ref
+    };
+    Some(VERSION.captures(tag)?.get(1)?.as_str().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WORKFLOW: &str = r#"name: CI
+on: [push]
+jobs:
+  test:
+    runs-on: ubuntu-latest
+    steps:
+      - uses: actions/checkout@v4
+      - name: Setup
+        uses: "actions/setup-node@v3.8.1"
+        with:
+          node-version: 20
+      - uses: github/codeql-action/init@v3
+      - uses: docker/build-push-action@4a13e500e55cf31b7a5d59a38ab2040ab0f42f56 # v5.1.0
+      - uses: ./.github/actions/local
+      - uses: docker://alpine:3.19
+      - uses: acme/deploy@main
+      # - uses: actions/cache@v3
+  release:
+    uses: acme/workflows/.github/workflows/release.yml@v2
+"#;
+
+    fn summary(deps: &[Dependency]) -> Vec<(&str, Option<&str>, Option<&str>)> {
+        deps.iter()
+            .map(|d| (d.name.as_str(), d.version.as_deref(), d.location.as_deref()))
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_workflow() {
+        let deps = parse_workflow(WORKFLOW, ".github/workflows/ci.yml");
+        assert_eq!(
+            summary(&deps),
+            vec![
+                ("actions/checkout", Some("4"), Some(".github/workflows/ci.yml:7")),
+                ("actions/setup-node", Some("3.8.1"), Some(".github/workflows/ci.yml:9")),
+                ("github/codeql-action/init", Some("3"), Some(".github/workflows/ci.yml:12")),
+                ("docker/build-push-action", Some("5.1.0"), Some(".github/workflows/ci.yml:13")),
+                ("acme/deploy", None, Some(".github/workflows/ci.yml:16")),
+                (
+                    "acme/workflows/.github/workflows/release.yml",
+                    Some("2"),
+                    Some(".github/workflows/ci.yml:19")
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_pinned_version() {
+        let sha = "b4ffde65f46336ab88eb53be808477a3936bae11";
+        assert_eq!(pinned_version(sha, Some("v4.1.1")).as_deref(), Some("4.1.1"));
+        assert_eq!(pinned_version(sha, Some("pin@v4.1.1")).as_deref(), Some("4.1.1"));
+        assert_eq!(pinned_version(sha, Some("tag=v4.1.1 (latest)")).as_deref(), Some("4.1.1"));
+        assert_eq!(pinned_version(sha, None), None);
+        assert_eq!(pinned_version("v4", Some("ignored")).as_deref(), Some("4"));
+        assert_eq!(pinned_version("release/v1", None), None);
+    }
+}
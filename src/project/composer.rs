@@ -0,0 +1,343 @@
+//! composer.json and composer.lock scanning
+
+use super::{Dependency, ProjectInfo};
+use serde_json::Value;
+use std::fs;
+
+/// The public repository, searched after every custom one unless disabled
+const PACKAGIST: &str = "https://repo.packagist.org";
+
+/// Custom `repositories` entries; packagist.org is implied unless disabled
+#[derive(Debug, Default, PartialEq, Eq)]
+struct Repositories {
+    /// `composer` repositories (Satis, Private Packagist, mirrors)
+    composer: Vec<String>,
+    /// `vcs`/`git`/`github`/... repository URLs
+    vcs: Vec<String>,
+    /// `path` repository directories
+    path: Vec<String>,
+    packagist_disabled: bool,
+}
+
+pub fn scan() -> Option<ProjectInfo> {
+    let content = fs::read_to_string("composer.json").ok()?;
+    let manifest: Value = serde_json::from_str(&content).ok()?;
+    let lock = fs::read_to_string("composer.lock")
+        .ok()
+        .and_then(|lock| serde_json::from_str::<Value>(&lock).ok());
+
+    let repos = parse_repositories(&manifest);
+    // Packages served from local path repositories are named in their own composer.json
+    let local_names: Vec<String> = repos
+        .path
+        .iter()
+        .filter_map(|dir| fs::read_to_string(format!("{dir}/composer.json")).ok())
+        .filter_map(|json| serde_json::from_str::<Value>(&json).ok())
+        .filter_map(|pkg| pkg.get("name")?.as_str().map(String::from))
+        .collect();
+
+    let packages = resolve(&manifest, lock.as_ref(), &repos, &local_names);
+    if packages.is_empty() {
+        return None;
+    }
+
+    Some(ProjectInfo { file: "composer.json", source: "composer", packages })
+}
+
+fn resolve(
+    manifest: &Value,
+    lock: Option<&Value>,
+    repos: &Repositories,
+    local_names: &[String],
+) -> Vec<Dependency> {
+    let required = required_packages(manifest);
+    let locked = lock.map(locked_packages).unwrap_or_default();
+
+    let mut packages = Vec::new();
+    for (name, constraint) in &required {
+        let entry = locked.iter().find(|p| p.get("name").and_then(Value::as_str) == Some(name));
+        packages.extend(entry.map_or_else(
+            || unlocked_dependency(name, constraint, repos, local_names),
+            |entry| locked_dependency(entry, repos),
+        ));
+    }
+
+    for entry in &locked {
+        let Some(name) = entry.get("name").and_then(Value::as_str) else { continue };
+        if !required.iter().any(|(n, _)| n == name)
+            && let Some(dep) = locked_dependency(entry, repos)
+        {
+            packages.push(Dependency { transitive: true, ..dep });
+        }
+    }
+    packages
+}
+
+/// `require` and `require-dev` entries, minus platform requirements (`php`, `ext-*`)
+fn required_packages(manifest: &Value) -> Vec<(String, String)> {
+    ["require", "require-dev"]
+        .iter()
+        .filter_map(|section| manifest.get(section)?.as_object())
+        .flatten()
+        .filter(|(name, _)| name.contains('/'))
+        .map(|(name, c)| (name.clone(), c.as_str().unwrap_or_default().to_string()))
+        .collect()
+}
+
+fn locked_packages(lock: &Value) -> Vec<Value> {
+    ["packages", "packages-dev"]
+        .iter()
+        .filter_map(|section| lock.get(section)?.as_array())
+        .flatten()
+        .cloned()
+        .collect()
+}
+
+/// A lock entry routed to the repository it was installed from; None when it
+/// came from a path or VCS repository
+fn locked_dependency(entry: &Value, repos: &Repositories) -> Option<Dependency> {
+    let name = entry.get("name")?.as_str()?;
+    let dist_type = entry.get("dist").and_then(|d| d.get("type")).and_then(Value::as_str);
+    if dist_type == Some("path") {
+        return None;
+    }
+    let source_url = entry.get("source").and_then(|s| s.get("url")).and_then(Value::as_str);
+    if source_url.is_some_and(|url| repos.vcs.iter().any(|r| same_repo(r, url))) {
+        return None;
+    }
+
+    // Private repositories that serve their own dist archives name themselves; any
+    // other package is searched for the way Composer would have found it
+    let dist_url = entry.get("dist").and_then(|d| d.get("url")).and_then(Value::as_str);
+    let registry =
+        match dist_url.and_then(|url| repos.composer.iter().find(|r| host(r) == host(url))) {
+            Some(repo) => Some(repo.clone()),
+            None => search_order(repos)?,
+        };
+
+    let version = entry.get("version")?.as_str()?;
+    Some(Dependency {
+        version: (!version.starts_with("dev-"))
+            .then(|| version.strip_prefix('v').unwrap_or(version).to_string()),
+        registry,
+        ..Dependency::new(name)
+    })
+}
+
+/// Without a lockfile, packages named by a path or VCS repository are skipped and
+/// the rest are searched for the way Composer would
+fn unlocked_dependency(
+    name: &str,
+    constraint: &str,
+    repos: &Repositories,
+    local_names: &[String],
+) -> Option<Dependency> {
+    if local_names.iter().any(|n| n == name) || repos.vcs.iter().any(|r| repo_name(r) == name) {
+        return None;
+    }
+    let registry = search_order(repos)?;
+    let version = constraint.strip_prefix('v').unwrap_or(constraint);
+    Some(Dependency {
+        version: version
+            .chars()
+            .all(|c| c.is_ascii_digit() || c == '.')
+            .then(|| version.to_string())
+            .filter(|v| !v.is_empty()),
+        registry,
+        ..Dependency::new(name)
+    })
+}
+
+/// Where Composer looks for a package: custom `composer` repositories in the order
+/// declared, then packagist.org unless disabled, as a space-separated registry list.
+/// `Some(None)` is packagist.org alone; `None` means no repository is left to ask
+#[allow(clippy::option_option)]
+fn search_order(repos: &Repositories) -> Option<Option<String>> {
+    let mut order = repos.composer.clone();
+    if !repos.packagist_disabled {
+        if order.is_empty() {
+            return Some(None);
+        }
+        order.push(PACKAGIST.to_string());
+    }
+    (!order.is_empty()).then(|| Some(order.join(" ")))
+}
+
+fn parse_repositories(manifest: &Value) -> Repositories {
+    let mut repos = Repositories::default();
+    // Either a list or an object keyed by repository name
+    let entries: Vec<(Option<&str>, &Value)> = match manifest.get("repositories") {
+        Some(Value::Array(list)) => list.iter().map(|r| (None, r)).collect(),
+        Some(Value::Object(map)) => map.iter().map(|(k, r)| (Some(k.as_str()), r)).collect(),
+        _ => Vec::new(),
+    };
+
+    for (key, repo) in entries {
+        if key == Some("packagist.org") && repo == &Value::Bool(false)
+            || repo.get("packagist.org") == Some(&Value::Bool(false))
+        {
+            repos.packagist_disabled = true;
+            continue;
+        }
+        let Some(url) = repo.get("url").and_then(Value::as_str) else { continue };
+        let url = url.trim_end_matches('/').to_string();
+        match repo.get("type").and_then(Value::as_str) {
+            Some("composer") => repos.composer.push(url),
+            Some("path") => repos.path.push(url),
+            Some("vcs" | "git" | "github" | "gitlab" | "bitbucket" | "hg" | "svn" | "fossil") => {
+                repos.vcs.push(url);
+            }
+            _ => {}
+        }
+    }
+    repos
+}
+
+fn host(url: &str) -> &str {
+    let rest = url.split_once("://").map_or(url, |(_, r)| r);
+    rest.split('/').next().unwrap_or(rest)
+}
+
+/// Repository URLs compared without scheme or `.git` suffix
+fn same_repo(a: &str, b: &str) -> bool {
+    let normalize = |url: &str| {
+        let rest = url.split_once("://").map_or(url, |(_, r)| r);
+        rest.trim_end_matches('/').trim_end_matches(".git").replace(':', "/").to_lowercase()
+    };
+    normalize(a).trim_start_matches("git@") == normalize(b).trim_start_matches("git@")
+}
+
+/// `vendor/name` guessed from a VCS URL like `https://github.com/vendor/name.git`
+fn repo_name(url: &str) -> String {
+    let path = url.trim_end_matches('/').trim_end_matches(".git").replace(':', "/");
+    let mut segments = path.rsplit('/');
+    let name = segments.next().unwrap_or_default();
+    let vendor = segments.next().unwrap_or_default();
+    format!("{vendor}/{name}").to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COMPOSER_JSON: &str = r#"{
+        "name": "acme/app",
+        "require": {
+            "php": "^8.2",
+            "ext-json": "*",
+            "monolog/monolog": "^3.5",
+            "acme/private-lib": "^1.0",
+            "acme/forked": "dev-main",
+            "acme/local": "*"
+        },
+        "require-dev": {
+            "phpunit/phpunit": "10.5.3"
+        },
+        "repositories": [
+            {"type": "composer", "url": "https://repo.acme.com/"},
+            {"type": "vcs", "url": "https://github.com/acme/forked.git"},
+            {"type": "path", "url": "../local"}
+        ]
+    }"#;
+
+    const COMPOSER_LOCK: &str = r#"{
+        "packages": [
+            {"name": "monolog/monolog", "version": "3.5.0",
+             "dist": {"type": "zip", "url": "https://api.github.com/repos/Seldaek/monolog/zipball/abc"},
+             "notification-url": "https://packagist.org/downloads/"},
+            {"name": "psr/log", "version": "3.0.0",
+             "dist": {"type": "zip", "url": "https://api.github.com/repos/php-fig/log/zipball/def"}},
+            {"name": "acme/private-lib", "version": "v1.2.0",
+             "dist": {"type": "zip", "url": "https://repo.acme.com/dists/acme/private-lib/1.2.0.zip"}},
+            {"name": "acme/forked", "version": "dev-main",
+             "source": {"type": "git", "url": "https://github.com/acme/forked.git", "reference": "abc"}},
+            {"name": "acme/local", "version": "dev-main",
+             "dist": {"type": "path", "url": "../local"}}
+        ],
+        "packages-dev": [
+            {"name": "phpunit/phpunit", "version": "10.5.3",
+             "dist": {"type": "zip", "url": "https://api.github.com/repos/sebastianbergmann/phpunit/zipball/0"}}
+        ]
+    }"#;
+
+    fn parse(lock: Option<&str>, local_names: &[String]) -> Vec<Dependency> {
+        let manifest: Value = serde_json::from_str(COMPOSER_JSON).unwrap_or_default();
+        let lock = lock.and_then(|l| serde_json::from_str::<Value>(l).ok());
+        resolve(&manifest, lock.as_ref(), &parse_repositories(&manifest), local_names)
+    }
+
+    fn summary(deps: &[Dependency]) -> Vec<(&str, Option<&str>, Option<&str>, bool)> {
+        deps.iter()
+            .map(|d| (d.name.as_str(), d.version.as_deref(), d.registry.as_deref(), d.transitive))
+            .collect()
+    }
+
+    /// The custom repository first, packagist.org after it
+    const SEARCH: Option<&str> = Some("https://repo.acme.com https://repo.packagist.org");
+
+    #[test]
+    fn test_resolve_with_lock() {
+        assert_eq!(
+            summary(&parse(Some(COMPOSER_LOCK), &[])),
+            vec![
+                ("acme/private-lib", Some("1.2.0"), Some("https://repo.acme.com"), false),
+                ("monolog/monolog", Some("3.5.0"), SEARCH, false),
+                ("phpunit/phpunit", Some("10.5.3"), SEARCH, false),
+                ("psr/log", Some("3.0.0"), SEARCH, true),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_resolve_without_lock() {
+        // Private packages must be looked up in the custom repository before packagist.org
+        let deps = parse(None, &["acme/local".to_string()]);
+        assert_eq!(
+            summary(&deps),
+            vec![
+                ("acme/private-lib", None, SEARCH, false),
+                ("monolog/monolog", None, SEARCH, false),
+                ("phpunit/phpunit", Some("10.5.3"), SEARCH, false),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_search_order() {
+        let repos = Repositories::default();
+        assert_eq!(search_order(&repos), Some(None));
+        let repos = Repositories { packagist_disabled: true, ..Repositories::default() };
+        assert_eq!(search_order(&repos), None);
+        let repos = Repositories {
+            composer: vec!["https://a.example.com".into(), "https://b.example.com".into()],
+            packagist_disabled: true,
+            ..Repositories::default()
+        };
+        assert_eq!(
+            search_order(&repos).flatten().as_deref(),
+            Some("https://a.example.com https://b.example.com")
+        );
+    }
+
+    #[test]
+    fn test_packagist_disabled() {
+        let manifest: Value = serde_json::from_str(
+            r#"{"require": {"acme/lib": "^1.0"},
+                "repositories": {"private": {"type": "composer", "url": "https://repo.acme.com"},
+                                 "packagist.org": false}}"#,
+        )
+        .unwrap_or_default();
+        let repos = parse_repositories(&manifest);
+        assert!(repos.packagist_disabled);
+        let deps = resolve(&manifest, None, &repos, &[]);
+        assert_eq!(deps[0].registry.as_deref(), Some("https://repo.acme.com"));
+    }
+
+    #[test]
+    fn test_repo_urls() {
+        assert_eq!(repo_name("https://github.com/Acme/Forked.git"), "acme/forked");
+        assert_eq!(repo_name("git@github.com:acme/forked.git"), "acme/forked");
+        assert!(same_repo("git@github.com:acme/forked.git", "https://github.com/acme/forked"));
+        assert_eq!(host("https://repo.acme.com/dists/x.zip"), "repo.acme.com");
+    }
+}
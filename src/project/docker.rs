@@ -0,0 +1,298 @@
+//! Dockerfile and compose file image scanning
+
+use super::{Dependency, ProjectInfo};
+use crate::sources::split_tag;
+use serde_yaml_ng::Value;
+use std::collections::HashMap;
+use std::fs;
+
+const COMPOSE_FILES: &[&str] =
+    &["compose.yaml", "compose.yml", "docker-compose.yml", "docker-compose.yaml"];
+
+/// Images from every Dockerfile in the directory, and from the compose file
+pub fn scan() -> Vec<ProjectInfo> {
+    let mut projects = Vec::new();
+
+    let mut dockerfiles: Vec<String> = fs::read_dir(".")
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+        .filter(|name| is_dockerfile(name))
+        .collect();
+    dockerfiles.sort();
+    let mut packages: Vec<Dependency> = Vec::new();
+    for content in dockerfiles.iter().filter_map(|f| fs::read_to_string(f).ok()) {
+        for dep in parse_dockerfile(&content) {
+            if !packages.contains(&dep) {
+                packages.push(dep);
+            }
+        }
+    }
+    if !packages.is_empty() {
+        projects.push(ProjectInfo { file: "Dockerfile", source: "docker", packages });
+    }
+
+    let compose = COMPOSE_FILES.iter().find_map(|f| Some((*f, fs::read_to_string(f).ok()?)));
+    if let Some((file, content)) = compose
+        && let Ok(doc) = serde_yaml_ng::from_str::<Value>(&content)
+    {
+        let packages = parse_compose(&doc, &|name| std::env::var(name).ok());
+        if !packages.is_empty() {
+            projects.push(ProjectInfo { file, source: "docker", packages });
+        }
+    }
+    projects
+}
+
+/// `Dockerfile`, `Dockerfile.prod`, `api.Dockerfile`
+fn is_dockerfile(name: &str) -> bool {
+    name == "Dockerfile"
+        || name.starts_with("Dockerfile.")
+        || name.ends_with(".Dockerfile")
+        || name.ends_with(".dockerfile")
+}
+
+/// Base images of every stage, with tags substituted from `ARG` defaults declared
+/// before the first `FROM`; references to earlier stages and `scratch` are skipped
+fn parse_dockerfile(content: &str) -> Vec<Dependency> {
+    let mut args: HashMap<String, String> = HashMap::new();
+    let mut stages: Vec<String> = Vec::new();
+    let mut packages: Vec<Dependency> = Vec::new();
+
+    for instruction in instructions(content) {
+        let mut words = instruction.split_whitespace();
+        let keyword = words.next().unwrap_or_default().to_ascii_uppercase();
+        match keyword.as_str() {
+            "ARG" if stages.is_empty() => {
+                for arg in words {
+                    if let Some((name, default)) = arg.split_once('=') {
+                        args.insert(name.to_string(), default.trim_matches('"').to_string());
+                    }
+                }
+            }
+            "FROM" => {
+                let mut words = words.skip_while(|w| w.starts_with("--"));
+                let Some(image) = words.next() else { continue };
+                if words.next().is_some_and(|w| w.eq_ignore_ascii_case("as"))
+                    && let Some(stage) = words.next()
+                {
+                    stages.push(stage.to_ascii_lowercase());
+                } else {
+                    stages.push(String::new());
+                }
+
+                let Some(image) = substitute(image, &|name| args.get(name).cloned()) else {
+                    continue;
+                };
+                if image == "scratch" || stages.contains(&image.to_ascii_lowercase()) {
+                    continue;
+                }
+                let dep = image_dependency(&image);
+                if !packages.contains(&dep) {
+                    packages.push(dep);
+                }
+            }
+            _ => {}
+        }
+    }
+    packages
+}
+
+/// Instructions with comments dropped and `\` continuation lines joined
+fn instructions(content: &str) -> Vec<String> {
+    let mut instructions = Vec::new();
+    let mut current = String::new();
+    for line in content.lines() {
+        let line = line.trim();
+        if line.starts_with('#') {
+            continue;
+        }
+        if let Some(partial) = line.strip_suffix('\\') {
+            current.push_str(partial);
+            current.push(' ');
+        } else {
+            current.push_str(line);
+            if !current.trim().is_empty() {
+                instructions.push(current.trim().to_string());
+            }
+            current.clear();
+        }
+    }
+    instructions
+}
+
+/// `services.*.image`, with `${VAR}` / `${VAR:-default}` interpolated
+fn parse_compose(doc: &Value, lookup: &dyn Fn(&str) -> Option<String>) -> Vec<Dependency> {
+    let Some(services) = doc.get("services").and_then(Value::as_mapping) else {
+        return Vec::new();
+    };
+
+    let mut packages: Vec<Dependency> = Vec::new();
+    for service in services.values() {
+        let Some(image) = service.get("image").and_then(Value::as_str) else { continue };
+        if let Some(dep) = substitute(image, lookup).map(|i| image_dependency(&i))
+            && !packages.contains(&dep)
+        {
+            packages.push(dep);
+        }
+    }
+    packages
+}
+
+/// An image reference as a dependency. Tags with a variant suffix are
+/// looked up as `image@*-variant` so they're compared against the same variant;
+/// `latest` and non-version tags (`alpine`, `bookworm`) have no version to compare
+pub(super) fn image_dependency(reference: &str) -> Dependency {
+    let (reference, digest) = match reference.split_once('@') {
+        Some((image, digest)) => (image, Some(digest.to_string())),
+        None => (reference, None),
+    };
+    let (image, tag) = match reference.rsplit_once(':') {
+        Some((image, tag)) if !tag.contains('/') => (image, Some(tag)),
+        _ => (reference, None),
+    };
+
+    let image = image
+        .strip_prefix("docker.io/")
+        .or_else(|| image.strip_prefix("index.docker.io/"))
+        .unwrap_or(image);
+    let image = image.strip_prefix("library/").unwrap_or(image);
+
+    match tag.and_then(|t| Some((t, split_tag(t)?))) {
+        Some((tag, (_, variant))) => {
+            let name =
+                if variant.is_empty() { image.to_string() } else { format!("{image}@*{variant}") };
+            Dependency { version: Some(tag.to_string()), digest, ..Dependency::new(name) }
+        }
+        None => Dependency { digest, ..Dependency::new(image) },
+    }
+}
+
+/// Expand `$VAR`, `${VAR}`, `${VAR:-default}` and `${VAR-default}`; None if a
+/// variable has no value or default
+fn substitute(value: &str, lookup: &dyn Fn(&str) -> Option<String>) -> Option<String> {
+    let mut result = String::new();
+    let mut rest = value;
+    while let Some(start) = rest.find('$') {
+        result.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        let (expr, remaining) = if let Some(braced) = after.strip_prefix('{') {
+            let end = braced.find('}')?;
+            (&braced[..end], &braced[end + 1..])
+        } else {
+            let end =
+                after.find(|c: char| !c.is_ascii_alphanumeric() && c != '_').unwrap_or(after.len());
+            (&after[..end], &after[end..])
+        };
+        let (name, default) = match expr.split_once(":-").or_else(|| expr.split_once('-')) {
+            Some((name, default)) => (name, Some(default)),
+            None => (expr, None),
+        };
+        let value = lookup(name).filter(|v| !v.is_empty()).or_else(|| default.map(String::from))?;
+        result.push_str(&value);
+        rest = remaining;
+    }
+    result.push_str(rest);
+    Some(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DOCKERFILE: &str = r"# syntax=docker/dockerfile:1
+ARG NODE_VERSION=20.11
+ARG DEBIAN=bookworm
+
+FROM --platform=$BUILDPLATFORM node:${NODE_VERSION}-alpine AS build
+WORKDIR /app
+RUN npm ci && \
+    npm run build
+
+FROM build AS test
+RUN npm test
+
+FROM python:3.12-slim-${DEBIAN} AS tools
+FROM scratch AS empty
+FROM $UNDEFINED_IMAGE
+FROM nginx:1.25.3@sha256:4c0fdaa8b6341bfdeca5f18f7837462c80cff90527ee35ef185571e1c327beac
+COPY --from=build /app/dist /usr/share/nginx/html
+";
+
+    fn summary(deps: &[Dependency]) -> Vec<(&str, Option<&str>)> {
+        deps.iter().map(|d| (d.name.as_str(), d.version.as_deref())).collect()
+    }
+
+    #[test]
+    fn test_parse_dockerfile() {
+        let deps = parse_dockerfile(DOCKERFILE);
+        assert_eq!(
+            summary(&deps),
+            vec![
+                ("node@*-alpine", Some("20.11-alpine")),
+                ("python@*-slim-bookworm", Some("3.12-slim-bookworm")),
+                ("nginx", Some("1.25.3")),
+            ]
+        );
+        assert_eq!(
+            deps[2].digest.as_deref(),
+            Some("sha256:4c0fdaa8b6341bfdeca5f18f7837462c80cff90527ee35ef185571e1c327beac")
+        );
+        assert_eq!(deps[0].digest, None);
+    }
+
+    #[test]
+    fn test_parse_compose() {
+        let doc: Value = serde_yaml_ng::from_str(
+            r"
+services:
+  db:
+    image: postgres:15.4
+  cache:
+    image: docker.io/library/redis:${REDIS_TAG:-7.2-alpine}
+  app:
+    build: .
+  proxy:
+    image: traefik:latest
+  private:
+    image: ghcr.io/acme/api:1.0.0
+  pinned:
+    image: postgres:15.4
+",
+        )
+        .unwrap_or_default();
+        assert_eq!(
+            summary(&parse_compose(&doc, &|_| None)),
+            vec![
+                ("postgres", Some("15.4")),
+                ("redis@*-alpine", Some("7.2-alpine")),
+                ("traefik", None),
+                ("ghcr.io/acme/api", Some("1.0.0")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_image_dependency() {
+        let dep = |r: &str| {
+            let d = image_dependency(r);
+            (d.name, d.version)
+        };
+        assert_eq!(dep("bitnami/redis:7.2.4"), ("bitnami/redis".into(), Some("7.2.4".into())));
+        assert_eq!(dep("debian:bookworm"), ("debian".into(), None));
+        assert_eq!(dep("ubuntu"), ("ubuntu".into(), None));
+        assert_eq!(
+            dep("localhost:5000/app:1.0-alpine"),
+            ("localhost:5000/app@*-alpine".into(), Some("1.0-alpine".into()))
+        );
+    }
+
+    #[test]
+    fn test_substitute() {
+        let lookup = |name: &str| (name == "TAG").then(|| "1.2".to_string());
+        assert_eq!(substitute("node:$TAG-alpine", &lookup), Some("node:1.2-alpine".into()));
+        assert_eq!(substitute("node:${TAG}", &lookup), Some("node:1.2".into()));
+        assert_eq!(substitute("node:${MISSING-20}", &lookup), Some("node:20".into()));
+        assert_eq!(substitute("node:${MISSING}", &lookup), None);
+    }
+}
@@ -0,0 +1,205 @@
+//! .NET project scanning: SDK-style projects, central package management and
+//! legacy packages.config
+
+use super::{Dependency, ProjectInfo};
+use roxmltree::{Document, Node};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+const PROJECT_EXTENSIONS: &[&str] = &["csproj", "fsproj", "vbproj"];
+const CENTRAL_VERSIONS: &str = "Directory.Packages.props";
+
+pub fn scan() -> Option<ProjectInfo> {
+    let mut projects: Vec<String> = fs::read_dir(".")
+        .ok()?
+        .filter_map(|entry| entry.ok()?.path().to_str().map(String::from))
+        .filter(|path| {
+            Path::new(path)
+                .extension()
+                .and_then(|e| e.to_str())
+                .is_some_and(|e| PROJECT_EXTENSIONS.contains(&e))
+        })
+        .collect();
+    projects.sort();
+
+    let mut packages: Vec<Dependency> = Vec::new();
+    let mut file = "packages.config";
+    if !projects.is_empty() {
+        let central =
+            find_central_versions().map(|c| parse_central_versions(&c)).unwrap_or_default();
+        for content in projects.iter().filter_map(|p| fs::read_to_string(p).ok()) {
+            for dep in parse_project(&content, &central) {
+                if !packages.iter().any(|d| d.name.eq_ignore_ascii_case(&dep.name)) {
+                    packages.push(dep);
+                }
+            }
+        }
+        file =
+            if projects.iter().all(|p| p.ends_with(".fsproj")) { "*.fsproj" } else { "*.csproj" };
+    } else if let Ok(config) = fs::read_to_string("packages.config") {
+        packages = parse_packages_config(&config);
+    }
+
+    if packages.is_empty() {
+        return None;
+    }
+
+    Some(ProjectInfo { file, source: "nuget", packages })
+}
+
+/// `MSBuild` picks up the nearest `Directory.Packages.props` in this or a parent directory
+fn find_central_versions() -> Option<String> {
+    let cwd = std::env::current_dir().ok()?;
+    cwd.ancestors().find_map(|dir| fs::read_to_string(dir.join(CENTRAL_VERSIONS)).ok())
+}
+
+/// `PackageReference` items, taking versions from the project, a `VersionOverride`
+/// or the central `PackageVersion` list
+fn parse_project(content: &str, central: &HashMap<String, String>) -> Vec<Dependency> {
+    let Ok(doc) = Document::parse(content) else { return Vec::new() };
+    let properties = properties(&doc);
+
+    doc.descendants()
+        .filter(|n| n.has_tag_name("PackageReference"))
+        .filter_map(|node| {
+            let name = node.attribute("Include")?;
+            let version = attribute_or_child(node, "VersionOverride")
+                .or_else(|| attribute_or_child(node, "Version"))
+                .or_else(|| central.get(&name.to_ascii_lowercase()).map(String::as_str))
+                .and_then(|v| resolve(v, &properties));
+            Some(Dependency { version, ..Dependency::new(name) })
+        })
+        .collect()
+}
+
+/// Central versions keyed by lowercased package ID (IDs are case-insensitive)
+fn parse_central_versions(content: &str) -> HashMap<String, String> {
+    let Ok(doc) = Document::parse(content) else { return HashMap::new() };
+    let properties = properties(&doc);
+    doc.descendants()
+        .filter(|n| n.has_tag_name("PackageVersion") || n.has_tag_name("GlobalPackageReference"))
+        .filter_map(|node| {
+            let name = node.attribute("Include")?;
+            let version = resolve(attribute_or_child(node, "Version")?, &properties)?;
+            Some((name.to_ascii_lowercase(), version))
+        })
+        .collect()
+}
+
+fn parse_packages_config(content: &str) -> Vec<Dependency> {
+    let Ok(doc) = Document::parse(content) else { return Vec::new() };
+    doc.descendants()
+        .filter(|n| n.has_tag_name("package"))
+        .filter_map(|node| {
+            let version = node.attribute("version").and_then(|v| resolve(v, &HashMap::new()));
+            Some(Dependency { version, ..Dependency::new(node.attribute("id")?) })
+        })
+        .collect()
+}
+
+fn attribute_or_child<'a>(node: Node<'a, '_>, name: &str) -> Option<&'a str> {
+    node.attribute(name)
+        .or_else(|| node.children().find(|n| n.has_tag_name(name))?.text())
+        .map(str::trim)
+}
+
+/// `<PropertyGroup>` values, for `$(Name)` references in versions
+fn properties(doc: &Document) -> HashMap<String, String> {
+    doc.descendants()
+        .filter(|n| n.has_tag_name("PropertyGroup"))
+        .flat_map(|group| group.children())
+        .filter(Node::is_element)
+        .filter_map(|p| Some((p.tag_name().name().to_string(), p.text()?.trim().to_string())))
+        .collect()
+}
+
+/// An exact version, with a `$(Property)` reference substituted; None for ranges
+/// (`[1.0,2.0)`) and floating versions (`6.*`)
+fn resolve(version: &str, properties: &HashMap<String, String>) -> Option<String> {
+    let version = match version.strip_prefix("$(").and_then(|v| v.strip_suffix(')')) {
+        Some(property) => properties.get(property)?.as_str(),
+        None => version,
+    };
+    (!version.is_empty() && !version.contains(['[', ']', '(', ')', ',', '*', '$']))
+        .then(|| version.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CSPROJ: &str = r#"<Project Sdk="Microsoft.NET.Sdk.Web">
+  <PropertyGroup>
+    <TargetFramework>net8.0</TargetFramework>
+    <SerilogVersion>3.1.1</SerilogVersion>
+  </PropertyGroup>
+  <ItemGroup>
+    <PackageReference Include="Newtonsoft.Json" Version="13.0.1" />
+    <PackageReference Include="Serilog" Version="$(SerilogVersion)" />
+    <PackageReference Include="Dapper">
+      <Version>2.1.24</Version>
+    </PackageReference>
+    <PackageReference Include="Polly" Version="[7.0,8.0)" />
+    <PackageReference Include="Microsoft.EntityFrameworkCore" />
+    <PackageReference Include="xunit" VersionOverride="2.6.2" />
+    <ProjectReference Include="..\Core\Core.csproj" />
+  </ItemGroup>
+</Project>
+"#;
+
+    const PACKAGES_PROPS: &str = r#"<Project>
+  <PropertyGroup>
+    <ManagePackageVersionsCentrally>true</ManagePackageVersionsCentrally>
+  </PropertyGroup>
+  <ItemGroup>
+    <PackageVersion Include="microsoft.entityframeworkcore" Version="8.0.0" />
+    <PackageVersion Include="xunit" Version="2.5.0" />
+    <GlobalPackageReference Include="Nerdbank.GitVersioning" Version="3.6.133" />
+  </ItemGroup>
+</Project>
+"#;
+
+    fn summary(deps: &[Dependency]) -> Vec<(&str, Option<&str>)> {
+        deps.iter().map(|d| (d.name.as_str(), d.version.as_deref())).collect()
+    }
+
+    #[test]
+    fn test_parse_project_with_central_versions() {
+        let central = parse_central_versions(PACKAGES_PROPS);
+        assert_eq!(
+            summary(&parse_project(CSPROJ, &central)),
+            vec![
+                ("Newtonsoft.Json", Some("13.0.1")),
+                ("Serilog", Some("3.1.1")),
+                ("Dapper", Some("2.1.24")),
+                ("Polly", None),
+                ("Microsoft.EntityFrameworkCore", Some("8.0.0")),
+                ("xunit", Some("2.6.2")),
+            ]
+        );
+        assert_eq!(central.get("nerdbank.gitversioning").map(String::as_str), Some("3.6.133"));
+    }
+
+    #[test]
+    fn test_parse_packages_config() {
+        let config = r#"<?xml version="1.0" encoding="utf-8"?>
+<packages>
+  <package id="EntityFramework" version="6.4.4" targetFramework="net48" />
+  <package id="log4net" version="2.0.15" targetFramework="net48" />
+</packages>"#;
+        assert_eq!(
+            summary(&parse_packages_config(config)),
+            vec![("EntityFramework", Some("6.4.4")), ("log4net", Some("2.0.15"))]
+        );
+    }
+
+    #[test]
+    fn test_resolve() {
+        let properties = HashMap::from([("V".to_string(), "1.2.3".to_string())]);
+        assert_eq!(resolve("$(V)", &properties), Some("1.2.3".to_string()));
+        assert_eq!(resolve("$(Missing)", &properties), None);
+        assert_eq!(resolve("6.*", &properties), None);
+        assert_eq!(resolve("[1.0,2.0)", &properties), None);
+    }
+}
@@ -0,0 +1,358 @@
+//! Gemfile and Gemfile.lock scanning
+
+use super::{Dependency, ProjectInfo};
+use std::collections::HashMap;
+use std::fs;
+
+/// Gem options that point somewhere other than rubygems.org
+const NON_REGISTRY_OPTIONS: &[&str] = &["path", "git", "github", "gitlab", "bitbucket"];
+
+#[derive(Debug, PartialEq, Eq)]
+struct Gem {
+    name: String,
+    constraints: Vec<String>,
+}
+
+pub fn scan() -> Option<ProjectInfo> {
+    let gemfile = fs::read_to_string("Gemfile").ok()?;
+    let locked = fs::read_to_string("Gemfile.lock")
+        .map(|lock| parse_gemfile_lock(&lock))
+        .unwrap_or_default();
+
+    let packages = resolve(&parse_gemfile(&gemfile), &locked);
+    if packages.is_empty() {
+        return None;
+    }
+
+    Some(ProjectInfo { file: "Gemfile", source: "gem", packages })
+}
+
+/// Combine declared gems with locked versions; locked gems the Gemfile
+/// doesn't mention are transitive. Declared requirements (`~> 7.1.3`, `>= 1.1, < 2.0`)
+/// carry over as the constraint
+fn resolve(gems: &[Gem], locked: &[(String, String)]) -> Vec<Dependency> {
+    let locked_versions: HashMap<_, _> = locked.iter().map(|(n, v)| (n.as_str(), v)).collect();
+
+    let mut packages: Vec<Dependency> = gems
+        .iter()
+        .map(|gem| Dependency {
+            version: locked_versions
+                .get(gem.name.as_str())
+                .map(|v| (*v).clone())
+                .or_else(|| exact_constraint(&gem.constraints)),
+            constraint: (!gem.constraints.is_empty()).then(|| gem.constraints.join(", ")),
+            ..Dependency::new(&gem.name)
+        })
+        .collect();
+
+    packages.extend(locked.iter().filter(|(name, _)| !gems.iter().any(|g| &g.name == name)).map(
+        |(name, version)| Dependency {
+            version: Some(version.clone()),
+            transitive: true,
+            ..Dependency::new(name)
+        },
+    ));
+    packages
+}
+
+/// Without a lockfile, only an exact requirement (`"1.2.3"` or `"= 1.2.3"`) tells us
+/// what's in use
+fn exact_constraint(constraints: &[String]) -> Option<String> {
+    match constraints {
+        [only] => {
+            let version = only.strip_prefix('=').unwrap_or(only).trim();
+            version.starts_with(|c: char| c.is_ascii_digit()).then(|| version.to_string())
+        }
+        _ => None,
+    }
+}
+
+fn parse_gemfile(content: &str) -> Vec<Gem> {
+    // Enclosing blocks, `true` for those whose gems come from elsewhere: `git`, `path`
+    // and `github` blocks, or a `source` other than rubygems.org. `group`, `platforms`
+    // and conditionals don't change where gems come from, so development and test
+    // gems are checked the same as top-level ones
+    let mut blocks: Vec<bool> = Vec::new();
+    let mut gems = Vec::new();
+
+    for line in content.lines() {
+        let line = line.split(" #").next().unwrap_or(line).trim();
+        if line == "end" {
+            blocks.pop();
+        } else if let Some(args) = line.strip_prefix("gem ").or_else(|| line.strip_prefix("gem(")) {
+            if !blocks.contains(&true) {
+                gems.extend(parse_gem_args(args.trim_end_matches(')')));
+            }
+        } else if opens_block(line) {
+            let keyword = line.split([' ', '(']).next().unwrap_or(line);
+            let args = line[keyword.len()..].trim_end_matches("do").trim();
+            let first = args.trim_matches(['(', ')']).split(',').next().and_then(unquote);
+            blocks.push(match keyword {
+                "source" => !first.is_some_and(is_rubygems),
+                keyword => NON_REGISTRY_OPTIONS.contains(&keyword),
+            });
+        }
+    }
+    gems
+}
+
+/// Whether a line opens a block closed by a later `end`
+fn opens_block(line: &str) -> bool {
+    const KEYWORDS: &[&str] = &["if", "unless", "case", "begin", "while", "until"];
+    line.ends_with(" do")
+        || line.contains(" do |")
+        || KEYWORDS.contains(&line.split(' ').next().unwrap_or(line))
+}
+
+fn is_rubygems(url: &str) -> bool {
+    let host = url.split_once("://").map_or(url, |(_, rest)| rest);
+    let host = host.split('/').next().unwrap_or(host);
+    host == "rubygems.org" || host.ends_with(".rubygems.org")
+}
+
+fn parse_gem_args(args: &str) -> Option<Gem> {
+    let mut parts = args.split(',').map(str::trim);
+    let name = unquote(parts.next()?)?.to_string();
+    let mut constraints = Vec::new();
+
+    for part in parts {
+        if let Some(constraint) = unquote(part) {
+            constraints.push(constraint.to_string());
+            continue;
+        }
+        // Options (`group: :test`, `:path => "..."`); only the source-changing ones are read
+        let Some((key, value)) = part.split_once("=>").or_else(|| part.split_once(':')) else {
+            continue;
+        };
+        let key = key.trim().trim_start_matches(':');
+        if NON_REGISTRY_OPTIONS.contains(&key)
+            || (key == "source" && !unquote(value.trim()).is_some_and(is_rubygems))
+        {
+            return None;
+        }
+    }
+
+    Some(Gem { name, constraints })
+}
+
+fn unquote(s: &str) -> Option<&str> {
+    s.strip_prefix('"')
+        .and_then(|s| s.strip_suffix('"'))
+        .or_else(|| s.strip_prefix('\'').and_then(|s| s.strip_suffix('\'')))
+}
+
+/// Resolved registry gems from the lockfile's rubygems.org `GEM` section, as (name,
+/// version). Gems locked from another `remote` come from a private source
+fn parse_gemfile_lock(content: &str) -> Vec<(String, String)> {
+    let mut locked = Vec::new();
+    let mut in_gem_specs = false;
+
+    for line in content.lines() {
+        if !line.starts_with(' ') {
+            in_gem_specs = false;
+            if line == "GEM" {
+                in_gem_specs = true;
+            }
+            continue;
+        }
+        if let Some(remote) = line.strip_prefix("  remote: ") {
+            in_gem_specs &= is_rubygems(remote);
+            continue;
+        }
+        // Specs are indented four spaces; their own dependencies six
+        let Some(spec) = line.strip_prefix("    ") else { continue };
+        if !in_gem_specs || spec.starts_with(' ') {
+            continue;
+        }
+        if let Some((name, version)) = spec.split_once(" (") {
+            let version = version.trim_end_matches(')');
+            // Platform gems lock as "1.16.3-x86_64-linux"
+            let version = version.split_once('-').map_or(version, |(v, _)| v);
+            if !locked.iter().any(|(n, _): &(String, String)| n == name) {
+                locked.push((name.to_string(), version.to_string()));
+            }
+        }
+    }
+    locked
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GEMFILE: &str = r#"source "https://rubygems.org"
+
+ruby "3.3.0"
+
+gem "rails", "~> 7.1.3"
+gem 'pg', '>= 1.1', '< 2.0'
+gem "puma", ">= 5.0" # web server
+gem "bootsnap", require: false
+gem "mylib", path: "../mylib"
+gem "forked", git: "https://github.com/org/forked.git"
+gem "other", github: "org/other"
+
+group :development, :test do
+  gem "debug", platforms: %i[ mri windows ]
+  gem "rspec-rails", "6.1.1"
+end
+
+gem "sidekiq", group: :production
+"#;
+
+    const LOCK: &str = r"GIT
+  remote: https://github.com/org/forked.git
+  specs:
+    forked (0.1.0)
+
+GEM
+  remote: https://rubygems.org/
+  specs:
+    bootsnap (1.18.3)
+      msgpack (~> 1.2)
+    msgpack (1.7.2)
+    nokogiri (1.16.3-x86_64-linux)
+    pg (1.5.6)
+    rails (7.1.3)
+
+PLATFORMS
+  x86_64-linux
+
+DEPENDENCIES
+  bootsnap
+  rails (~> 7.1.3)
+
+BUNDLED WITH
+   2.5.6
+";
+
+    #[test]
+    fn test_parse_gemfile() {
+        let gems = parse_gemfile(GEMFILE);
+        let names: Vec<_> = gems.iter().map(|g| g.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec!["rails", "pg", "puma", "bootsnap", "debug", "rspec-rails", "sidekiq"]
+        );
+        assert_eq!(gems[1].constraints, vec![">= 1.1", "< 2.0"]);
+    }
+
+    #[test]
+    fn test_parse_gemfile_skips_git_block() {
+        let gemfile = r#"git "https://github.com/rails/rails.git", branch: "main" do
+  gem "activesupport"
+  gem "actionpack"
+end
+gem "puma"
+"#;
+        let names: Vec<_> = parse_gemfile(gemfile).into_iter().map(|g| g.name).collect();
+        assert_eq!(names, vec!["puma"]);
+    }
+
+    #[test]
+    fn test_parse_gemfile_skips_path_block() {
+        let gemfile = r#"path "engines" do
+  gem "billing"
+  gem "admin"
+end
+gem "puma"
+"#;
+        let names: Vec<_> = parse_gemfile(gemfile).into_iter().map(|g| g.name).collect();
+        assert_eq!(names, vec!["puma"]);
+    }
+
+    #[test]
+    fn test_parse_gemfile_skips_github_block() {
+        let gemfile = r#"group :test do
+  github "org/testing-tools" do
+    gem "org-matchers"
+  end
+  gem "rspec"
+end
+"#;
+        let names: Vec<_> = parse_gemfile(gemfile).into_iter().map(|g| g.name).collect();
+        assert_eq!(names, vec!["rspec"]);
+    }
+
+    #[test]
+    fn test_parse_gemfile_skips_private_source_block() {
+        let gemfile = r#"source "https://rubygems.org"
+
+source "https://gems.acme.com" do
+  gem "acme-auth"
+  if ENV["ACME_BILLING"]
+    gem "acme-billing"
+  end
+end
+
+source "https://rubygems.org/" do
+  gem "rack"
+end
+gem "puma"
+"#;
+        let names: Vec<_> = parse_gemfile(gemfile).into_iter().map(|g| g.name).collect();
+        assert_eq!(names, vec!["rack", "puma"]);
+    }
+
+    #[test]
+    fn test_parse_gemfile_skips_source_option() {
+        let gemfile = r#"gem "acme-auth", "~> 2.0", source: "https://gems.acme.com"
+gem "rack", :source => "https://rubygems.org"
+"#;
+        let names: Vec<_> = parse_gemfile(gemfile).into_iter().map(|g| g.name).collect();
+        assert_eq!(names, vec!["rack"]);
+    }
+
+    #[test]
+    fn test_parse_gemfile_lock_skips_private_remote() {
+        let lock = r"GEM
+  remote: https://gems.acme.com/
+  specs:
+    acme-auth (2.1.0)
+
+GEM
+  remote: https://rubygems.org/
+  specs:
+    rack (3.1.7)
+";
+        assert_eq!(parse_gemfile_lock(lock), vec![("rack".to_string(), "3.1.7".to_string())]);
+    }
+
+    #[test]
+    fn test_parse_gemfile_lock() {
+        let locked = parse_gemfile_lock(LOCK);
+        assert_eq!(
+            locked,
+            vec![
+                ("bootsnap".to_string(), "1.18.3".to_string()),
+                ("msgpack".to_string(), "1.7.2".to_string()),
+                ("nokogiri".to_string(), "1.16.3".to_string()),
+                ("pg".to_string(), "1.5.6".to_string()),
+                ("rails".to_string(), "7.1.3".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_resolve_with_lock() {
+        let deps = resolve(&parse_gemfile(GEMFILE), &parse_gemfile_lock(LOCK));
+        let find = |name: &str| deps.iter().find(|d| d.name == name);
+        assert_eq!(find("rails").and_then(|d| d.version.as_deref()), Some("7.1.3"));
+        // Not locked, but pinned exactly in the Gemfile
+        assert_eq!(find("rspec-rails").and_then(|d| d.version.as_deref()), Some("6.1.1"));
+        assert_eq!(find("puma").and_then(|d| d.version.as_deref()), None);
+        assert!(find("msgpack").is_some_and(|d| d.transitive));
+        assert!(find("forked").is_none());
+        assert_eq!(find("rails").and_then(|d| d.constraint.as_deref()), Some("~> 7.1.3"));
+        assert_eq!(find("pg").and_then(|d| d.constraint.as_deref()), Some(">= 1.1, < 2.0"));
+        assert_eq!(find("bootsnap").and_then(|d| d.constraint.as_deref()), None);
+    }
+
+    #[test]
+    fn test_exact_constraint() {
+        assert_eq!(exact_constraint(&["1.2.3".to_string()]), Some("1.2.3".to_string()));
+        assert_eq!(exact_constraint(&["= 1.2.3".to_string()]), Some("1.2.3".to_string()));
+        assert_eq!(exact_constraint(&["~> 1.2".to_string()]), None);
+        assert_eq!(exact_constraint(&[]), None);
+    }
+}
@@ -0,0 +1,196 @@
+//! go.mod scanning
+
+use super::{Dependency, ProjectInfo};
+use std::fs;
+
+struct Replace<'a> {
+    old_path: &'a str,
+    old_version: Option<&'a str>,
+    new_path: &'a str,
+    /// None when the replacement is a local directory
+    new_version: Option<&'a str>,
+}
+
+pub fn scan() -> Option<ProjectInfo> {
+    let content = fs::read_to_string("go.mod").ok()?;
+    let packages = parse_go_mod(&content);
+
+    if packages.is_empty() {
+        return None;
+    }
+
+    Some(ProjectInfo { file: "go.mod", source: "go", packages })
+}
+
+fn parse_go_mod(content: &str) -> Vec<Dependency> {
+    let mut packages = Vec::new();
+    let mut requires = Vec::new();
+    let mut replaces = Vec::new();
+    let mut block: Option<&str> = None;
+
+    for (number, raw) in content.lines().enumerate() {
+        let (code, comment) = raw.split_once("//").unwrap_or((raw, ""));
+        let line = code.trim();
+        if line.is_empty() {
+            continue;
+        }
+        if line == ")" {
+            block = None;
+            continue;
+        }
+
+        // Inside a block every line belongs to the block's directive
+        let (verb, args) = if let Some(verb) = block {
+            (verb, line)
+        } else {
+            let (verb, args) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+            if args.trim() == "(" {
+                block = Some(verb);
+                continue;
+            }
+            (verb, args.trim())
+        };
+
+        let mut fields = args.split_whitespace();
+        match verb {
+            "go" => packages.push(toolchain(args, number + 1)),
+            "toolchain" if args != "default" => {
+                packages.push(toolchain(args.strip_prefix("go").unwrap_or(args), number + 1));
+            }
+            "require" => {
+                if let (Some(path), Some(version)) = (fields.next(), fields.next()) {
+                    let indirect = comment.trim_start().starts_with("indirect");
+                    requires.push((path, version, indirect));
+                }
+            }
+            "replace" => {
+                if let Some(replace) = parse_replace(args) {
+                    replaces.push(replace);
+                }
+            }
+            // exclude and retract only steer version selection; there is nothing to check
+            _ => {}
+        }
+    }
+
+    for (path, version, indirect) in requires {
+        let replacement = replaces
+            .iter()
+            .find(|r| r.old_path == path && r.old_version == Some(version))
+            .or_else(|| replaces.iter().find(|r| r.old_path == path && r.old_version.is_none()));
+
+        let (path, version) = match replacement {
+            Some(Replace { new_version: None, .. }) => continue, // Local checkout
+            Some(Replace { new_path, new_version: Some(v), .. }) => (*new_path, *v),
+            None => (path, version),
+        };
+
+        packages.push(Dependency {
+            version: Some(version.strip_prefix('v').unwrap_or(version).to_string()),
+            transitive: indirect,
+            ..Dependency::new(path)
+        });
+    }
+
+    packages.sort_by_key(|d| d.transitive); // Direct dependencies first
+    packages
+}
+
+/// `go` and `toolchain` directives are both checked as `go` against the Go release
+/// itself; the line number tells them apart
+fn toolchain(version: &str, line: usize) -> Dependency {
+    Dependency {
+        version: Some(version.to_string()),
+        source: Some("golang"),
+        location: Some(format!("go.mod:{line}")),
+        ..Dependency::new("go")
+    }
+}
+
+/// Parse `old [version] => new [version]`
+fn parse_replace(args: &str) -> Option<Replace<'_>> {
+    let (old, new) = args.split_once("=>")?;
+    let mut old = old.split_whitespace();
+    let mut new = new.split_whitespace();
+    Some(Replace {
+        old_path: old.next()?,
+        old_version: old.next(),
+        new_path: new.next()?,
+        new_version: new.next(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GO_MOD: &str = r"module example.com/app
+
+go 1.22.1
+
+toolchain go1.22.3
+
+require github.com/spf13/cobra v1.8.0
+
+require (
+	github.com/stretchr/testify v1.9.0
+	golang.org/x/sys v0.18.0 // indirect
+	github.com/old/lib v1.2.0
+	github.com/local/thing v0.1.0
+)
+
+replace github.com/old/lib => github.com/fork/lib v1.3.1
+
+replace (
+	github.com/local/thing => ../thing
+)
+
+exclude github.com/spf13/cobra v1.7.0
+";
+
+    fn summary(deps: &[Dependency]) -> Vec<(&str, Option<&str>, bool)> {
+        deps.iter().map(|d| (d.name.as_str(), d.version.as_deref(), d.transitive)).collect()
+    }
+
+    #[test]
+    fn test_parse_go_mod() {
+        assert_eq!(
+            summary(&parse_go_mod(GO_MOD)),
+            vec![
+                ("go", Some("1.22.1"), false),
+                ("go", Some("1.22.3"), false),
+                ("github.com/spf13/cobra", Some("1.8.0"), false),
+                ("github.com/stretchr/testify", Some("1.9.0"), false),
+                ("github.com/fork/lib", Some("1.3.1"), false),
+                ("golang.org/x/sys", Some("0.18.0"), true),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_toolchain_directives_use_golang_source() {
+        let deps = parse_go_mod(GO_MOD);
+        let toolchains: Vec<_> = deps.iter().filter(|d| d.source == Some("golang")).collect();
+        assert_eq!(toolchains.len(), 2);
+        assert!(toolchains.iter().all(|d| d.name == "go"));
+        assert_eq!(toolchains[1].location.as_deref(), Some("go.mod:5"));
+        assert!(deps.iter().filter(|d| d.name.contains('/')).all(|d| d.source.is_none()));
+    }
+
+    #[test]
+    fn test_version_specific_replace() {
+        let content = "require a.io/x v1.0.0\nrequire b.io/y v2.0.0\n\
+                       replace a.io/x v0.9.0 => c.io/x v0.9.1\nreplace b.io/y v2.0.0 => d.io/y v2.0.1\n";
+        assert_eq!(
+            summary(&parse_go_mod(content)),
+            vec![("a.io/x", Some("1.0.0"), false), ("d.io/y", Some("2.0.1"), false)]
+        );
+    }
+
+    #[test]
+    fn test_parse_replace() {
+        let r = parse_replace("a.io/x v1.0.0 => ./local");
+        assert!(r.is_some_and(|r| r.old_version == Some("v1.0.0") && r.new_version.is_none()));
+        assert!(parse_replace("a.io/x v1.0.0").is_none());
+    }
+}
@@ -0,0 +1,308 @@
+//! Gradle build script and version catalog scanning
+
+use super::{Dependency, ProjectInfo};
+use regex::Regex;
+use std::collections::HashMap;
+use std::fs;
+use std::sync::LazyLock;
+
+const BUILD_FILES: &[&str] = &["build.gradle.kts", "build.gradle"];
+const SETTINGS_FILES: &[&str] = &["settings.gradle.kts", "settings.gradle"];
+const CATALOG: &str = "gradle/libs.versions.toml";
+
+/// Quoted `group:artifact:version` coordinates, optionally with a classifier or `@ext`
+static COORDINATE: LazyLock<Regex> = LazyLock::new(|| {
+    #[allow(clippy::unwrap_used)]
+    Regex::new(r#"["']([\w.\-]+):([\w.\-]+):([^"':@\s]+)(?::[\w.\-]+)?(?:@\w+)?["']"#).unwrap()
+});
+
+/// `maven { url "..." }`, `maven { url = uri("...") }` and `maven("...")`
+static MAVEN_REPO: LazyLock<Regex> = LazyLock::new(|| {
+    #[allow(clippy::unwrap_used)]
+    Regex::new(r#"maven\s*(?:\(\s*(?:url\s*=\s*)?(?:uri\()?|\{[^}]*?url\s*=?\s*\(?\s*(?:uri\()?)\s*["']([^"']+)["']"#)
+        .unwrap()
+});
+
+/// Simple string variables: `ext.x = "1.0"`, `def x = '1.0'`, `val x = "1.0"`, `x: "1.0"`
+static VARIABLE: LazyLock<Regex> = LazyLock::new(|| {
+    #[allow(clippy::unwrap_used)]
+    Regex::new(
+        r#"(?m)^\s*(?:ext\.|def\s+|val\s+|set\(\s*["'])?([\w.]+)["']?\s*[=:,]\s*["']([^"'$]+)["']"#,
+    )
+    .unwrap()
+});
+
+pub fn scan() -> Option<ProjectInfo> {
+    let build = BUILD_FILES.iter().find_map(|f| Some((*f, fs::read_to_string(f).ok()?)));
+    let catalog = fs::read_to_string(CATALOG).ok();
+    if build.is_none() && catalog.is_none() {
+        return None;
+    }
+
+    let settings = SETTINGS_FILES.iter().find_map(|f| fs::read_to_string(f).ok());
+    let scripts: Vec<&str> =
+        build.iter().map(|(_, b)| b.as_str()).chain(settings.as_deref()).collect();
+    let registry = repositories(&scripts);
+
+    let mut variables =
+        fs::read_to_string("gradle.properties").map(|p| parse_properties(&p)).unwrap_or_default();
+    let mut packages = Vec::new();
+    if let Some((_, content)) = &build {
+        variables.extend(parse_variables(content));
+        packages.extend(parse_build_script(content, &variables));
+    }
+    if let Some(catalog) = &catalog {
+        for dep in parse_catalog(catalog) {
+            if !packages.iter().any(|d: &Dependency| d.name == dep.name) {
+                packages.push(dep);
+            }
+        }
+    }
+    if packages.is_empty() {
+        return None;
+    }
+    for dep in &mut packages {
+        dep.registry.clone_from(&registry);
+    }
+
+    let file = build.map_or(CATALOG, |(file, _)| file);
+    Some(ProjectInfo { file, source: "maven", packages })
+}
+
+/// Dependency coordinates from a build script, resolving `$var` / `${var}` versions
+fn parse_build_script(content: &str, variables: &HashMap<String, String>) -> Vec<Dependency> {
+    let mut packages: Vec<Dependency> = Vec::new();
+    for line in content.lines() {
+        let line = line.split("//").next().unwrap_or(line);
+        for caps in COORDINATE.captures_iter(line) {
+            let name = format!("{}:{}", &caps[1], &caps[2]);
+            if packages.iter().any(|d| d.name == name) {
+                continue;
+            }
+            let version = caps[3]
+                .strip_prefix('$')
+                .map_or_else(
+                    || Some(caps[3].to_string()),
+                    |var| variables.get(var.trim_matches(['{', '}'])).cloned(),
+                )
+                .filter(|v| is_plain_version(v));
+            packages.push(Dependency { version, ..Dependency::new(name) });
+        }
+    }
+    packages
+}
+
+/// `[libraries]` from a version catalog, with `version.ref` resolved against `[versions]`
+fn parse_catalog(content: &str) -> Vec<Dependency> {
+    let Ok(doc) = toml::from_str::<toml::Value>(content) else { return Vec::new() };
+    let versions = doc.get("versions").and_then(toml::Value::as_table);
+    let Some(libraries) = doc.get("libraries").and_then(toml::Value::as_table) else {
+        return Vec::new();
+    };
+
+    let version_of = |v: &toml::Value| -> Option<String> {
+        match v {
+            toml::Value::String(s) => Some(s.clone()),
+            // Rich versions: { strictly = "..." } / { require = "..." } / { prefer = "..." }
+            toml::Value::Table(t) => ["strictly", "require", "prefer"]
+                .iter()
+                .find_map(|k| t.get(*k)?.as_str().map(String::from)),
+            _ => None,
+        }
+    };
+
+    libraries
+        .values()
+        .filter_map(|lib| {
+            let (name, version) = match lib {
+                toml::Value::String(s) => {
+                    let mut parts = s.splitn(3, ':');
+                    let name = format!("{}:{}", parts.next()?, parts.next()?);
+                    (name, parts.next().map(String::from))
+                }
+                toml::Value::Table(t) => {
+                    let name = match t.get("module").and_then(toml::Value::as_str) {
+                        Some(module) => module.to_string(),
+                        None => {
+                            format!("{}:{}", t.get("group")?.as_str()?, t.get("name")?.as_str()?)
+                        }
+                    };
+                    let version = match t.get("version") {
+                        Some(toml::Value::Table(v)) if v.contains_key("ref") => {
+                            let key = v.get("ref")?.as_str()?;
+                            versions.and_then(|vs| vs.get(key)).and_then(version_of)
+                        }
+                        Some(v) => version_of(v),
+                        None => None,
+                    };
+                    (name, version)
+                }
+                _ => return None,
+            };
+            Some(Dependency {
+                version: version.filter(|v| is_plain_version(v)),
+                ..Dependency::new(name)
+            })
+        })
+        .collect()
+}
+
+/// Declared Maven repositories as a whitespace-separated registry list, or None
+/// when only Maven Central is used
+fn repositories(scripts: &[&str]) -> Option<String> {
+    let mut urls: Vec<String> = Vec::new();
+    for script in scripts {
+        let mut found = Vec::new();
+        if script.contains("google()") {
+            found.push("https://dl.google.com/dl/android/maven2".to_string());
+        }
+        if script.contains("gradlePluginPortal()") {
+            found.push("https://plugins.gradle.org/m2".to_string());
+        }
+        found.extend(MAVEN_REPO.captures_iter(script).map(|c| c[1].to_string()));
+        for url in found {
+            if !urls.contains(&url) {
+                urls.push(url);
+            }
+        }
+    }
+    (!urls.is_empty()).then(|| urls.join(" "))
+}
+
+fn parse_variables(content: &str) -> HashMap<String, String> {
+    VARIABLE
+        .captures_iter(content)
+        .map(|c| (c[1].trim_start_matches("ext.").to_string(), c[2].to_string()))
+        .collect()
+}
+
+fn parse_properties(content: &str) -> HashMap<String, String> {
+    content
+        .lines()
+        .filter(|l| !l.trim_start().starts_with('#'))
+        .filter_map(|l| l.split_once('='))
+        .map(|(k, v)| (k.trim().to_string(), v.trim().to_string()))
+        .collect()
+}
+
+/// Exact versions only; dynamic (`1.+`, `latest.release`) and ranges can't be compared
+fn is_plain_version(version: &str) -> bool {
+    !version.is_empty()
+        && !version.contains(['+', '[', ']', '(', ')', ',', '$'])
+        && !version.starts_with("latest.")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BUILD_GRADLE: &str = r#"plugins {
+    id 'java'
+}
+
+ext {
+    jacksonVersion = '2.16.0'
+}
+def junitVersion = "5.10.1"
+
+repositories {
+    mavenCentral()
+    google()
+    maven { url "https://maven.acme.com/releases" }
+}
+
+dependencies {
+    implementation 'com.google.guava:guava:32.1.3-jre'
+    implementation "com.fasterxml.jackson.core:jackson-databind:$jacksonVersion"
+    implementation platform("org.springframework.boot:spring-boot-dependencies:3.2.0")
+    testImplementation "org.junit.jupiter:junit-jupiter:${junitVersion}"
+    runtimeOnly 'org.postgresql:postgresql:42.+'
+    // implementation 'commented:out:1.0'
+    implementation 'org.lwjgl:lwjgl:3.3.3:natives-linux'
+    implementation project(':core')
+}
+"#;
+
+    const BUILD_GRADLE_KTS: &str = r#"val ktorVersion = "2.3.7"
+
+repositories {
+    mavenCentral()
+    maven("https://jitpack.io")
+    maven { url = uri("https://maven.acme.com/snapshots") }
+}
+
+dependencies {
+    implementation("io.ktor:ktor-server-core:$ktorVersion")
+    implementation(libs.okhttp)
+    testImplementation(kotlin("test"))
+}
+"#;
+
+    const CATALOG_TOML: &str = r#"[versions]
+kotlin = "1.9.22"
+junit = { strictly = "5.10.1" }
+
+[libraries]
+guava = "com.google.guava:guava:33.0.0-jre"
+kotlin-stdlib = { module = "org.jetbrains.kotlin:kotlin-stdlib", version.ref = "kotlin" }
+okhttp = { group = "com.squareup.okhttp3", name = "okhttp", version = "4.12.0" }
+junit = { module = "org.junit.jupiter:junit-jupiter", version.ref = "junit" }
+bom-managed = { module = "org.slf4j:slf4j-api" }
+
+[plugins]
+kotlin-jvm = { id = "org.jetbrains.kotlin.jvm", version.ref = "kotlin" }
+"#;
+
+    fn summary(deps: &[Dependency]) -> Vec<(&str, Option<&str>)> {
+        deps.iter().map(|d| (d.name.as_str(), d.version.as_deref())).collect()
+    }
+
+    #[test]
+    fn test_parse_build_gradle() {
+        let deps = parse_build_script(BUILD_GRADLE, &parse_variables(BUILD_GRADLE));
+        assert_eq!(
+            summary(&deps),
+            vec![
+                ("com.google.guava:guava", Some("32.1.3-jre")),
+                ("com.fasterxml.jackson.core:jackson-databind", Some("2.16.0")),
+                ("org.springframework.boot:spring-boot-dependencies", Some("3.2.0")),
+                ("org.junit.jupiter:junit-jupiter", Some("5.10.1")),
+                ("org.postgresql:postgresql", None),
+                ("org.lwjgl:lwjgl", Some("3.3.3")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_build_gradle_kts() {
+        let deps = parse_build_script(BUILD_GRADLE_KTS, &parse_variables(BUILD_GRADLE_KTS));
+        assert_eq!(summary(&deps), vec![("io.ktor:ktor-server-core", Some("2.3.7"))]);
+    }
+
+    #[test]
+    fn test_parse_catalog() {
+        assert_eq!(
+            summary(&parse_catalog(CATALOG_TOML)),
+            vec![
+                ("org.slf4j:slf4j-api", None),
+                ("com.google.guava:guava", Some("33.0.0-jre")),
+                ("org.junit.jupiter:junit-jupiter", Some("5.10.1")),
+                ("org.jetbrains.kotlin:kotlin-stdlib", Some("1.9.22")),
+                ("com.squareup.okhttp3:okhttp", Some("4.12.0")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_repositories() {
+        assert_eq!(
+            repositories(&[BUILD_GRADLE]).as_deref(),
+            Some("https://dl.google.com/dl/android/maven2 https://maven.acme.com/releases")
+        );
+        assert_eq!(
+            repositories(&[BUILD_GRADLE_KTS]).as_deref(),
+            Some("https://jitpack.io https://maven.acme.com/snapshots")
+        );
+        assert_eq!(repositories(&["repositories { mavenCentral() }"]), None);
+    }
+}
@@ -0,0 +1,166 @@
+//! Chart.yaml and Chart.lock scanning (requirements.yaml/.lock for v1 charts)
+
+use super::{Dependency, ProjectInfo};
+use serde_yaml_ng::Value;
+use std::fs;
+
+pub fn scan() -> Option<ProjectInfo> {
+    let chart: Value = serde_yaml_ng::from_str(&fs::read_to_string("Chart.yaml").ok()?).ok()?;
+    // apiVersion v1 charts declare dependencies in a separate requirements.yaml
+    let requirements = fs::read_to_string("requirements.yaml")
+        .ok()
+        .and_then(|r| serde_yaml_ng::from_str::<Value>(&r).ok());
+    let lock = ["Chart.lock", "requirements.lock"]
+        .iter()
+        .find_map(|file| serde_yaml_ng::from_str::<Value>(&fs::read_to_string(file).ok()?).ok());
+
+    let declared =
+        if chart.get("dependencies").is_some() { Some(&chart) } else { requirements.as_ref() };
+    let packages = parse_dependencies(declared?, lock.as_ref());
+    if packages.is_empty() {
+        return None;
+    }
+
+    Some(ProjectInfo { file: "Chart.yaml", source: "helm", packages })
+}
+
+/// Charts from a repository, at their locked version or the version they
+/// declare. Subcharts vendored under `charts/` (no repository, or `file://`) are skipped
+fn parse_dependencies(chart: &Value, lock: Option<&Value>) -> Vec<Dependency> {
+    let locked = lock.map(entries).unwrap_or_default();
+    entries(chart)
+        .into_iter()
+        .filter(|(_, repository, _)| !repository.is_empty() && !repository.starts_with("file://"))
+        .map(|(name, repository, version)| {
+            let pinned = locked
+                .iter()
+                .find(|(n, r, _)| {
+                    *n == name && r.trim_end_matches('/') == repository.trim_end_matches('/')
+                })
+                .map(|(_, _, v)| v.clone());
+            // An exact version is bumped by editing Chart.yaml, so only ranges limit updates
+            let range = (!version.starts_with(|c: char| c.is_ascii_digit())
+                || version.contains(['x', 'X', '*', ' ']))
+            .then(|| version.clone())
+            .filter(|v| !v.is_empty());
+            Dependency {
+                version: pinned.or_else(|| lower_bound(&version)),
+                registry: Some(repository),
+                constraint: range,
+                ..Dependency::new(name)
+            }
+        })
+        .collect()
+}
+
+/// `(name, repository, version)` for each entry of a `dependencies` list
+fn entries(doc: &Value) -> Vec<(String, String, String)> {
+    let deps = doc.get("dependencies").and_then(Value::as_sequence).into_iter().flatten();
+    deps.filter_map(|dep| {
+        let field = |key: &str| match dep.get(key) {
+            Some(Value::String(s)) => s.trim().to_string(),
+            Some(Value::Number(n)) => n.to_string(),
+            _ => String::new(),
+        };
+        let name = field("name");
+        (!name.is_empty()).then(|| (name, field("repository"), field("version")))
+    })
+    .collect()
+}
+
+/// The oldest version a constraint like `^18.6.0`, `~1.2`, `>= 1.0 < 2.0` or `12.x` allows
+fn lower_bound(constraint: &str) -> Option<String> {
+    let first = constraint.split([',', '|']).next()?.trim();
+    let version = first.split_whitespace().find_map(|clause| {
+        let clause = clause.trim_start_matches(['^', '~', '>', '=', 'v']);
+        clause.starts_with(|c: char| c.is_ascii_digit()).then_some(clause)
+    })?;
+    let version = version.replace(['x', 'X', '*'], "0");
+    Some(version.trim_end_matches('.').to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CHART: &str = r#"apiVersion: v2
+name: platform
+version: 0.4.0
+appVersion: "1.16.0"
+dependencies:
+  - name: redis
+    version: ^18.6.0
+    repository: https://charts.bitnami.com/bitnami/
+  - name: postgresql
+    version: 13.2.24
+    repository: oci://registry-1.docker.io/bitnamicharts
+    condition: postgresql.enabled
+  - name: common
+    version: 2.x.x
+    repository: "@bitnami"
+  - name: ingress-nginx
+    version: ">= 4.8.0 < 5.0.0"
+    repository: alias:ingress
+  - name: api
+    version: 0.1.0
+    repository: file://../api
+  - name: vendored
+    version: 1.0.0
+"#;
+
+    const LOCK: &str = r"dependencies:
+- name: redis
+  repository: https://charts.bitnami.com/bitnami
+  version: 18.6.1
+- name: common
+  repository: '@bitnami'
+  version: 2.14.1
+digest: sha256:abc
+generated: 2024-01-15T10:00:00Z
+";
+
+    type Summary<'a> = (&'a str, Option<&'a str>, Option<&'a str>, Option<&'a str>);
+
+    fn summary(deps: &[Dependency]) -> Vec<Summary<'_>> {
+        deps.iter()
+            .map(|d| {
+                let (version, registry) = (d.version.as_deref(), d.registry.as_deref());
+                (d.name.as_str(), version, registry, d.constraint.as_deref())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_dependencies() {
+        let chart: Value = serde_yaml_ng::from_str(CHART).unwrap_or_default();
+        let lock: Value = serde_yaml_ng::from_str(LOCK).unwrap_or_default();
+        assert_eq!(
+            summary(&parse_dependencies(&chart, Some(&lock))),
+            vec![
+                (
+                    "redis",
+                    Some("18.6.1"),
+                    Some("https://charts.bitnami.com/bitnami/"),
+                    Some("^18.6.0")
+                ),
+                (
+                    "postgresql",
+                    Some("13.2.24"),
+                    Some("oci://registry-1.docker.io/bitnamicharts"),
+                    None
+                ),
+                ("common", Some("2.14.1"), Some("@bitnami"), Some("2.x.x")),
+                ("ingress-nginx", Some("4.8.0"), Some("alias:ingress"), Some(">= 4.8.0 < 5.0.0")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lower_bound() {
+        assert_eq!(lower_bound("^18.6.0").as_deref(), Some("18.6.0"));
+        assert_eq!(lower_bound("~1.2").as_deref(), Some("1.2"));
+        assert_eq!(lower_bound("12.x").as_deref(), Some("12.0"));
+        assert_eq!(lower_bound(">= 4.8.0 < 5.0.0").as_deref(), Some("4.8.0"));
+        assert_eq!(lower_bound("*"), None);
+    }
+}
@@ -0,0 +1,98 @@
+//! Term parsing and requirements shared by the Hex scanners (mix.exs, rebar.config)
+
+/// Without a lockfile, only an exact requirement (`"== 1.2.3"`) tells us what's in use
+pub(super) fn exact_requirement(requirement: &str) -> Option<String> {
+    let version = requirement.strip_prefix("==").unwrap_or(requirement).trim();
+    version
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '+'))
+        .then(|| version.to_string())
+        .filter(|v| v.starts_with(|c: char| c.is_ascii_digit()))
+}
+
+/// Remove line comments starting with `marker`, leaving string contents alone
+pub(super) fn strip_comments(content: &str, marker: char) -> String {
+    content
+        .lines()
+        .map(|line| {
+            let mut in_string = false;
+            for (i, c) in line.char_indices() {
+                match c {
+                    '"' => in_string = !in_string,
+                    c if c == marker && !in_string => return &line[..i],
+                    _ => {}
+                }
+            }
+            line
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Contents of the bracketed term `s` starts with, up to its matching close
+pub(super) fn enclosed(s: &str) -> Option<&str> {
+    let mut depth = 0;
+    let mut in_string = false;
+    for (i, c) in s.char_indices() {
+        match c {
+            '"' => in_string = !in_string,
+            _ if in_string => {}
+            '[' | '{' | '(' => depth += 1,
+            ']' | '}' | ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(&s[1..i]);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Split a term list on its top-level commas
+pub(super) fn split_terms(s: &str) -> Vec<&str> {
+    let mut terms = Vec::new();
+    let mut depth = 0;
+    let mut in_string = false;
+    let mut start = 0;
+    for (i, c) in s.char_indices() {
+        match c {
+            '"' => in_string = !in_string,
+            _ if in_string => {}
+            '[' | '{' | '(' => depth += 1,
+            ']' | '}' | ')' => depth -= 1,
+            ',' if depth == 0 => {
+                terms.push(s[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    terms.push(s[start..].trim());
+    terms.retain(|t| !t.is_empty());
+    terms
+}
+
+pub(super) fn unquote(s: &str) -> Option<&str> {
+    s.strip_prefix('"')?.strip_suffix('"')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exact_requirement() {
+        assert_eq!(exact_requirement("== 2.7.1"), Some("2.7.1".to_string()));
+        assert_eq!(exact_requirement("2.7.1"), Some("2.7.1".to_string()));
+        assert_eq!(exact_requirement("~> 2.7"), None);
+        assert_eq!(exact_requirement(">= 0.0.0"), None);
+    }
+
+    #[test]
+    fn test_split_terms() {
+        let terms = split_terms(r#"{:a, "~> 1.0"}, {:b, only: [:dev, :test]}, "x,y""#);
+        assert_eq!(terms, vec![r#"{:a, "~> 1.0"}"#, "{:b, only: [:dev, :test]}", r#""x,y""#]);
+    }
+}
@@ -0,0 +1,340 @@
+//! Kubernetes manifest, kustomization and Helm values image scanning
+
+use super::docker::image_dependency;
+use super::{Dependency, ProjectInfo};
+use serde::Deserialize;
+use serde_yaml_ng::Value;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Directories that hold vendored or generated YAML rather than our manifests
+const SKIP_DIRS: &[&str] = &["node_modules", "vendor", "target"];
+/// Conventional homes for manifests; YAML anywhere below one of these is read
+const MANIFEST_DIRS: &[&str] =
+    &["k8s", "kubernetes", "manifests", "deploy", "deployment", "deployments", "charts", "helm"];
+/// Files that mark a kustomization or Helm chart root, read along with everything below it
+const ROOT_MARKERS: &[&str] =
+    &["kustomization.yaml", "kustomization.yml", "Kustomization", "Chart.yaml"];
+const CONTAINER_KEYS: &[&str] = &["containers", "initContainers", "ephemeralContainers"];
+
+/// An image reference and the `key: value` line it can be found on
+#[derive(Debug, PartialEq, Eq)]
+struct Reference {
+    image: String,
+    key: &'static str,
+    value: String,
+}
+
+/// Every image referenced from manifest YAML under the current directory, each
+/// with the file and line it's declared on
+pub fn scan() -> Option<ProjectInfo> {
+    let mut files = Vec::new();
+    yaml_files(Path::new("."), false, &mut files);
+    files.sort();
+
+    let mut packages = Vec::new();
+    for path in files {
+        let Ok(content) = fs::read_to_string(&path) else { continue };
+        let display = path.strip_prefix(".").unwrap_or(&path).display().to_string();
+        let display = display.trim_start_matches('/');
+        packages.extend(locate(&content, &parse_documents(&content), display));
+    }
+    if packages.is_empty() {
+        return None;
+    }
+
+    Some(ProjectInfo { file: "Kubernetes manifests", source: "docker", packages })
+}
+
+/// YAML files in manifest directories and kustomization or chart roots. Symlinks
+/// are never followed, so a link back up the tree can't loop the walk
+fn yaml_files(dir: &Path, in_manifests: bool, files: &mut Vec<PathBuf>) {
+    let name = dir.file_name().map(|n| n.to_string_lossy().to_ascii_lowercase());
+    let in_manifests = in_manifests
+        || name.is_some_and(|n| MANIFEST_DIRS.contains(&n.as_str()))
+        || ROOT_MARKERS.iter().any(|marker| dir.join(marker).is_file());
+    let Ok(entries) = fs::read_dir(dir) else { return };
+    for entry in entries.flatten() {
+        let Ok(file_type) = entry.file_type() else { continue };
+        let path = entry.path();
+        let name = entry.file_name().to_string_lossy().into_owned();
+        if file_type.is_dir() {
+            if !name.starts_with('.') && !SKIP_DIRS.contains(&name.as_str()) {
+                yaml_files(&path, in_manifests, files);
+            }
+        } else if in_manifests
+            && file_type.is_file()
+            && path
+                .extension()
+                .is_some_and(|e| e.eq_ignore_ascii_case("yaml") || e.eq_ignore_ascii_case("yml"))
+        {
+            files.push(path);
+        }
+    }
+}
+
+/// Image references from every document in a multi-document YAML file
+fn parse_documents(content: &str) -> Vec<Reference> {
+    let mut references = Vec::new();
+    for document in serde_yaml_ng::Deserializer::from_str(content) {
+        let Ok(doc) = Value::deserialize(document) else { return references };
+        if doc.get("kind").and_then(Value::as_str).is_none_or(|k| k == "Kustomization") {
+            references.extend(kustomize_images(&doc));
+        }
+        collect(&doc, &mut references);
+    }
+    references
+}
+
+/// `spec.containers[].image` wherever it's nested (`Pod`, `Deployment`, `CronJob`, ...)
+/// and Helm-style `image: {repository, tag}` values
+fn collect(value: &Value, references: &mut Vec<Reference>) {
+    match value {
+        Value::Mapping(map) => {
+            for (key, value) in map {
+                match key.as_str() {
+                    Some(k) if CONTAINER_KEYS.contains(&k) => {
+                        let images = value.as_sequence().into_iter().flatten();
+                        for image in images.filter_map(|c| c.get("image")?.as_str()) {
+                            references.push(Reference {
+                                image: image.to_string(),
+                                key: "image",
+                                value: image.to_string(),
+                            });
+                        }
+                    }
+                    Some("image") if value.get("repository").is_some() => {
+                        references.extend(helm_image(value));
+                    }
+                    _ => collect(value, references),
+                }
+            }
+        }
+        Value::Sequence(items) => items.iter().for_each(|v| collect(v, references)),
+        _ => {}
+    }
+}
+
+/// `image: {registry, repository, tag}`; an empty tag defers to the chart's `appVersion`
+fn helm_image(image: &Value) -> Option<Reference> {
+    let repository = image.get("repository")?.as_str()?;
+    let mut reference = match image.get("registry").and_then(Value::as_str) {
+        Some(registry) if !registry.is_empty() => format!("{registry}/{repository}"),
+        _ => repository.to_string(),
+    };
+    if let Some(tag) = image.get("tag").and_then(scalar).filter(|t| !t.is_empty()) {
+        reference = format!("{reference}:{tag}");
+    }
+    Some(Reference { image: reference, key: "repository", value: repository.to_string() })
+}
+
+/// kustomization `images:` overrides: `newName`/`newTag` replace the named image
+fn kustomize_images(doc: &Value) -> Vec<Reference> {
+    let images = doc.get("images").and_then(Value::as_sequence).into_iter().flatten();
+    images
+        .filter(|i| i.get("newTag").is_some() || i.get("newName").is_some())
+        .filter_map(|image| {
+            let name = image.get("name")?.as_str()?;
+            let new_name = image.get("newName").and_then(Value::as_str).unwrap_or(name);
+            let reference = image
+                .get("newTag")
+                .and_then(scalar)
+                .map_or_else(|| new_name.to_string(), |tag| format!("{new_name}:{tag}"));
+            Some(Reference { image: reference, key: "name", value: name.to_string() })
+        })
+        .collect()
+}
+
+/// Tags are often unquoted numbers (`tag: 15`)
+fn scalar(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        _ => None,
+    }
+}
+
+/// Dependencies for the references, located at their line in the file. References
+/// are found in document order, so each search starts after the previous match
+fn locate(content: &str, references: &[Reference], file: &str) -> Vec<Dependency> {
+    let lines: Vec<&str> = content.lines().collect();
+    let mut cursor = 0;
+    let mut packages = Vec::new();
+    for reference in references {
+        // Template expressions and variables aren't real image names
+        if reference.image.contains(['{', '$']) {
+            continue;
+        }
+        let matches = |line: &&str| {
+            let line = line.trim_start().trim_start_matches("- ");
+            line.starts_with(reference.key)
+                && line[reference.key.len()..].trim_start().starts_with(':')
+                && line.contains(reference.value.as_str())
+        };
+        let line = lines[cursor..]
+            .iter()
+            .position(matches)
+            .map(|i| cursor + i)
+            .or_else(|| lines.iter().position(matches));
+        if let Some(line) = line {
+            cursor = line + 1;
+        }
+        let location = line.map_or_else(|| file.to_string(), |l| format!("{file}:{}", l + 1));
+        packages
+            .push(Dependency { location: Some(location), ..image_dependency(&reference.image) });
+    }
+    packages
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DEPLOYMENT: &str = r"apiVersion: apps/v1
+kind: Deployment
+metadata:
+  name: api
+spec:
+  template:
+    spec:
+      initContainers:
+        - name: migrate
+          image: acme/migrate:1.4.0
+      containers:
+        - name: api
+          image: node:20.11-alpine
+        - name: sidecar
+          image: nginx
+---
+apiVersion: batch/v1
+kind: CronJob
+metadata:
+  name: backup
+spec:
+  jobTemplate:
+    spec:
+      template:
+        spec:
+          containers:
+            - name: backup
+              image: postgres:15.4
+";
+
+    fn summary(deps: &[Dependency]) -> Vec<(&str, Option<&str>, Option<&str>)> {
+        deps.iter()
+            .map(|d| (d.name.as_str(), d.version.as_deref(), d.location.as_deref()))
+            .collect()
+    }
+
+    #[test]
+    fn test_manifests() {
+        let deps = locate(DEPLOYMENT, &parse_documents(DEPLOYMENT), "k8s/api.yaml");
+        assert_eq!(
+            summary(&deps),
+            vec![
+                ("acme/migrate", Some("1.4.0"), Some("k8s/api.yaml:10")),
+                ("node@*-alpine", Some("20.11-alpine"), Some("k8s/api.yaml:13")),
+                ("nginx", None, Some("k8s/api.yaml:15")),
+                ("postgres", Some("15.4"), Some("k8s/api.yaml:28")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_kustomization() {
+        let content = r"resources:
+  - deployment.yaml
+images:
+  - name: postgres
+    newTag: 16.1
+  - name: redis
+    newName: bitnami/redis
+    newTag: 7.2.4
+  - name: unchanged
+";
+        assert_eq!(
+            summary(&locate(content, &parse_documents(content), "kustomization.yaml")),
+            vec![
+                ("postgres", Some("16.1"), Some("kustomization.yaml:4")),
+                ("bitnami/redis", Some("7.2.4"), Some("kustomization.yaml:6")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_helm_values() {
+        let content = r#"replicaCount: 2
+image:
+  repository: acme/api
+  tag: "2.3.1"
+redis:
+  image:
+    registry: docker.io
+    repository: bitnami/redis
+    tag: 7.2.4-debian-12-r0
+worker:
+  image:
+    repository: ghcr.io/acme/worker
+    tag: 1.0.0
+chart:
+  image:
+    repository: acme/defaulted
+    tag: ""
+templated:
+  image: "{{ .Values.image.repository }}"
+"#;
+        assert_eq!(
+            summary(&locate(content, &parse_documents(content), "values.yaml")),
+            vec![
+                ("acme/api", Some("2.3.1"), Some("values.yaml:3")),
+                ("bitnami/redis@*-debian-12-r0", Some("7.2.4-debian-12-r0"), Some("values.yaml:8")),
+                ("ghcr.io/acme/worker", Some("1.0.0"), Some("values.yaml:12")),
+                ("acme/defaulted", None, Some("values.yaml:16")),
+            ]
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_yaml_files() {
+        let root = std::env::temp_dir().join(format!("latest-k8s-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        for dir in ["services/api/k8s/base", "overlay", "docs", "node_modules/pkg/deploy"] {
+            fs::create_dir_all(root.join(dir)).unwrap();
+        }
+        for file in [
+            "services/api/k8s/base/deployment.yaml",
+            "overlay/kustomization.yaml",
+            "overlay/patch.yml",
+            "docs/mkdocs.yml",
+            "node_modules/pkg/deploy/pod.yaml",
+            "ci.yaml",
+        ] {
+            fs::write(root.join(file), "kind: Pod\n").unwrap();
+        }
+        // A link back up the tree must not be followed
+        std::os::unix::fs::symlink(&root, root.join("overlay/loop")).unwrap();
+
+        let mut files = Vec::new();
+        yaml_files(&root, false, &mut files);
+        files.sort();
+        let found: Vec<_> = files.iter().filter_map(|f| f.strip_prefix(&root).ok()).collect();
+        assert_eq!(
+            found,
+            vec![
+                Path::new("overlay/kustomization.yaml"),
+                Path::new("overlay/patch.yml"),
+                Path::new("services/api/k8s/base/deployment.yaml"),
+            ]
+        );
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn test_repeated_image_lines() {
+        let content = "containers:\n  - image: redis:7.0\n---\ncontainers:\n  - image: redis:7.0\n";
+        let deps = locate(content, &parse_documents(content), "a.yaml");
+        let locations: Vec<_> = deps.iter().filter_map(|d| d.location.as_deref()).collect();
+        assert_eq!(locations, vec!["a.yaml:2", "a.yaml:5"]);
+    }
+}
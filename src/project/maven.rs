@@ -0,0 +1,279 @@
+//! pom.xml scanning
+
+use super::{Dependency, ProjectInfo};
+use roxmltree::{Document, Node};
+use std::collections::HashMap;
+use std::fs;
+
+/// groupId Maven assumes for plugins that don't declare one
+const DEFAULT_PLUGIN_GROUP: &str = "org.apache.maven.plugins";
+
+pub fn scan() -> Option<ProjectInfo> {
+    let content = fs::read_to_string("pom.xml").ok()?;
+    let packages = parse_pom(&content)?;
+
+    if packages.is_empty() {
+        return None;
+    }
+
+    Some(ProjectInfo { file: "pom.xml", source: "maven", packages })
+}
+
+fn parse_pom(content: &str) -> Option<Vec<Dependency>> {
+    let doc = Document::parse(content).ok()?;
+    let project = doc.root_element();
+    let properties = properties(project);
+    let registry = repositories(project);
+    let own_groups: Vec<&str> = [Some(project), child(project, "parent")]
+        .into_iter()
+        .flatten()
+        .filter_map(|p| text(p, "groupId"))
+        .collect();
+
+    let build = child(project, "build");
+    let sections = [
+        (child(project, "dependencies"), "dependency"),
+        (
+            child(project, "dependencyManagement").and_then(|m| child(m, "dependencies")),
+            "dependency",
+        ),
+        (build.and_then(|b| child(b, "plugins")), "plugin"),
+        (
+            build.and_then(|b| child(b, "pluginManagement")).and_then(|m| child(m, "plugins")),
+            "plugin",
+        ),
+    ];
+
+    let mut packages: Vec<Dependency> = Vec::new();
+    for (section, tag) in sections {
+        for node in section.into_iter().flat_map(|s| s.children()).filter(|n| n.has_tag_name(tag)) {
+            let group = text(node, "groupId")
+                .or_else(|| (tag == "plugin").then_some(DEFAULT_PLUGIN_GROUP))
+                .map(|g| interpolate(g, &properties));
+            let (Some(Some(group)), Some(artifact)) = (group, text(node, "artifactId")) else {
+                continue;
+            };
+            let name =
+                format!("{group}:{}", interpolate(artifact, &properties).unwrap_or_default());
+            if packages.iter().any(|d| d.name == name) {
+                continue;
+            }
+            // Other modules of the same build share its version and aren't published
+            let declared = text(node, "version");
+            if own_groups.contains(&group.as_str()) && declared.is_some_and(is_project_version) {
+                continue;
+            }
+            let version =
+                declared.and_then(|v| interpolate(v, &properties)).filter(|v| is_plain_version(v));
+            packages.push(Dependency {
+                version,
+                registry: registry.clone(),
+                ..Dependency::new(name)
+            });
+        }
+    }
+    Some(packages)
+}
+
+fn child<'a, 'i>(node: Node<'a, 'i>, name: &str) -> Option<Node<'a, 'i>> {
+    node.children().find(|n| n.has_tag_name(name))
+}
+
+fn text<'a>(node: Node<'a, '_>, name: &str) -> Option<&'a str> {
+    child(node, name)?.text().map(str::trim)
+}
+
+/// `<properties>` plus the built-in `project.version` / `project.parent.version`
+fn properties(project: Node) -> HashMap<String, String> {
+    let mut properties: HashMap<String, String> = child(project, "properties")
+        .into_iter()
+        .flat_map(|p| p.children())
+        .filter(Node::is_element)
+        .filter_map(|p| Some((p.tag_name().name().to_string(), p.text()?.trim().to_string())))
+        .collect();
+    let parent_version = child(project, "parent").and_then(|p| text(p, "version"));
+    if let Some(version) = parent_version {
+        properties.insert("project.parent.version".to_string(), version.to_string());
+    }
+    if let Some(version) = text(project, "version").or(parent_version) {
+        properties.insert("project.version".to_string(), version.to_string());
+    }
+    properties
+}
+
+/// Declared `<repositories>` as a whitespace-separated registry list, or None for
+/// Maven Central alone
+fn repositories(project: Node) -> Option<String> {
+    let urls: Vec<&str> = child(project, "repositories")?
+        .children()
+        .filter(|n| n.has_tag_name("repository"))
+        .filter_map(|r| text(r, "url"))
+        .collect();
+    (!urls.is_empty()).then(|| urls.join(" "))
+}
+
+/// Substitute `${name}` references; None if one can't be resolved
+fn interpolate(value: &str, properties: &HashMap<String, String>) -> Option<String> {
+    let mut result = value.to_string();
+    // Bounded so self-referencing properties can't loop forever
+    for _ in 0..10 {
+        let Some(start) = result.find("${") else { return Some(result) };
+        let end = start + result[start..].find('}')?;
+        let replacement = properties.get(&result[start + 2..end])?;
+        result.replace_range(start..=end, replacement);
+    }
+    None
+}
+
+fn is_project_version(version: &str) -> bool {
+    matches!(version, "${project.version}" | "${project.parent.version}")
+}
+
+/// Exact versions only; ranges like `[1.0,2.0)` can't be compared
+fn is_plain_version(version: &str) -> bool {
+    !version.is_empty() && !version.contains(['[', ']', '(', ')', ',', '$'])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const POM: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<project xmlns="http://maven.apache.org/POM/4.0.0">
+  <modelVersion>4.0.0</modelVersion>
+  <parent>
+    <groupId>org.springframework.boot</groupId>
+    <artifactId>spring-boot-starter-parent</artifactId>
+    <version>3.2.0</version>
+  </parent>
+  <groupId>com.acme</groupId>
+  <artifactId>app</artifactId>
+  <version>1.0.0-SNAPSHOT</version>
+
+  <properties>
+    <guava.version>32.1.3-jre</guava.version>
+    <jackson.version>2.16.0</jackson.version>
+  </properties>
+
+  <repositories>
+    <repository>
+      <id>acme</id>
+      <url>https://maven.acme.com/releases</url>
+    </repository>
+  </repositories>
+
+  <dependencyManagement>
+    <dependencies>
+      <dependency>
+        <groupId>com.fasterxml.jackson</groupId>
+        <artifactId>jackson-bom</artifactId>
+        <version>${jackson.version}</version>
+        <type>pom</type>
+        <scope>import</scope>
+      </dependency>
+    </dependencies>
+  </dependencyManagement>
+
+  <dependencies>
+    <dependency>
+      <groupId>com.google.guava</groupId>
+      <artifactId>guava</artifactId>
+      <version>${guava.version}</version>
+    </dependency>
+    <dependency>
+      <groupId>org.springframework.boot</groupId>
+      <artifactId>spring-boot-starter-web</artifactId>
+    </dependency>
+    <dependency>
+      <groupId>com.acme</groupId>
+      <artifactId>shared</artifactId>
+      <version>${project.version}</version>
+    </dependency>
+    <dependency>
+      <groupId>junit</groupId>
+      <artifactId>junit</artifactId>
+      <version>[4.12,5.0)</version>
+    </dependency>
+    <dependency>
+      <groupId>org.example</groupId>
+      <artifactId>missing</artifactId>
+      <version>${undefined.version}</version>
+    </dependency>
+  </dependencies>
+
+  <build>
+    <plugins>
+      <plugin>
+        <artifactId>maven-compiler-plugin</artifactId>
+        <version>3.11.0</version>
+      </plugin>
+    </plugins>
+  </build>
+</project>
+"#;
+
+    fn summary(deps: &[Dependency]) -> Vec<(&str, Option<&str>)> {
+        deps.iter().map(|d| (d.name.as_str(), d.version.as_deref())).collect()
+    }
+
+    #[test]
+    fn test_parse_pom() {
+        let deps = parse_pom(POM).unwrap_or_default();
+        assert_eq!(
+            summary(&deps),
+            vec![
+                ("com.google.guava:guava", Some("32.1.3-jre")),
+                ("org.springframework.boot:spring-boot-starter-web", None),
+                ("junit:junit", None),
+                ("org.example:missing", None),
+                ("com.fasterxml.jackson:jackson-bom", Some("2.16.0")),
+                ("org.apache.maven.plugins:maven-compiler-plugin", Some("3.11.0")),
+            ]
+        );
+        assert!(
+            deps.iter().all(|d| d.registry.as_deref() == Some("https://maven.acme.com/releases"))
+        );
+    }
+
+    #[test]
+    fn test_parse_pom_without_repositories() {
+        let pom = "<project><dependencies><dependency><groupId>a</groupId>\
+                   <artifactId>b</artifactId><version>1.0</version></dependency></dependencies></project>";
+        let deps = parse_pom(pom).unwrap_or_default();
+        assert_eq!(summary(&deps), vec![("a:b", Some("1.0"))]);
+        assert_eq!(deps[0].registry, None);
+        assert!(parse_pom("not xml").is_none());
+    }
+
+    #[test]
+    fn test_parse_pom_skips_sibling_modules() {
+        let pom = "<project><parent><groupId>com.acme</groupId><artifactId>platform</artifactId>\
+                   <version>2.1.0-SNAPSHOT</version></parent><artifactId>api</artifactId>\
+                   <dependencies>\
+                   <dependency><groupId>com.acme</groupId><artifactId>core</artifactId>\
+                   <version>${project.parent.version}</version></dependency>\
+                   <dependency><groupId>com.acme</groupId><artifactId>client</artifactId>\
+                   <version>1.4.0</version></dependency>\
+                   <dependency><groupId>org.other</groupId><artifactId>lib</artifactId>\
+                   <version>${project.version}</version></dependency>\
+                   </dependencies></project>";
+        let deps = parse_pom(pom).unwrap_or_default();
+        assert_eq!(
+            summary(&deps),
+            vec![("com.acme:client", Some("1.4.0")), ("org.other:lib", Some("2.1.0-SNAPSHOT"))]
+        );
+    }
+
+    #[test]
+    fn test_interpolate() {
+        let properties = HashMap::from([
+            ("a".to_string(), "1".to_string()),
+            ("b".to_string(), "${a}.2".to_string()),
+            ("loop".to_string(), "${loop}".to_string()),
+        ]);
+        assert_eq!(interpolate("${b}.3", &properties), Some("1.2.3".to_string()));
+        assert_eq!(interpolate("plain", &properties), Some("plain".to_string()));
+        assert_eq!(interpolate("${nope}", &properties), None);
+        assert_eq!(interpolate("${loop}", &properties), None);
+    }
+}
@@ -0,0 +1,180 @@
+//! mix.exs and mix.lock scanning
+
+use super::hex::{enclosed, exact_requirement, split_terms, strip_comments, unquote};
+use super::{Dependency, ProjectInfo};
+use std::fs;
+
+/// Dependency options that point somewhere other than the public Hex repository
+const NON_HEX_OPTIONS: &[&str] = &["path", "git", "github", "in_umbrella", "organization", "repo"];
+
+#[derive(Debug, PartialEq, Eq)]
+struct MixDep {
+    /// Name the project refers to it by (the key in mix.lock)
+    app: String,
+    /// Name on hex.pm, which differs when the `hex:` option is used
+    package: String,
+    requirement: Option<String>,
+}
+
+pub fn scan() -> Option<ProjectInfo> {
+    let mix = fs::read_to_string("mix.exs").ok()?;
+    let locked =
+        fs::read_to_string("mix.lock").map(|lock| parse_mix_lock(&lock)).unwrap_or_default();
+
+    let packages = resolve(&parse_mix_exs(&mix), &locked);
+    if packages.is_empty() {
+        return None;
+    }
+
+    Some(ProjectInfo { file: "mix.exs", source: "hex", packages })
+}
+
+/// Combine declared deps with locked versions; locked packages mix.exs
+/// doesn't mention are transitive
+fn resolve(deps: &[MixDep], locked: &[(String, String, String)]) -> Vec<Dependency> {
+    let mut packages: Vec<Dependency> = deps
+        .iter()
+        .map(|dep| Dependency {
+            version: locked
+                .iter()
+                .find(|(app, _, _)| *app == dep.app)
+                .map(|(_, _, version)| version.clone())
+                .or_else(|| dep.requirement.as_deref().and_then(exact_requirement)),
+            constraint: dep.requirement.clone(),
+            ..Dependency::new(&dep.package)
+        })
+        .collect();
+
+    packages.extend(locked.iter().filter(|(app, _, _)| !deps.iter().any(|d| &d.app == app)).map(
+        |(_, package, version)| Dependency {
+            version: Some(version.clone()),
+            transitive: true,
+            ..Dependency::new(package)
+        },
+    ));
+    packages
+}
+
+fn parse_mix_exs(content: &str) -> Vec<MixDep> {
+    let content = strip_comments(content, '#');
+    let Some(start) = content.find("defp deps").or_else(|| content.find("def deps")) else {
+        return Vec::new();
+    };
+    let body = &content[start..];
+    let Some(list) = body.find('[').and_then(|i| enclosed(&body[i..])) else {
+        return Vec::new();
+    };
+
+    split_terms(list).into_iter().filter_map(parse_dep_tuple).collect()
+}
+
+/// Parse `{:name, "~> 1.0", only: :test}`; None for deps that don't come from hex.pm
+fn parse_dep_tuple(term: &str) -> Option<MixDep> {
+    let mut fields = split_terms(enclosed(term)?).into_iter();
+    let app = fields.next()?.strip_prefix(':')?.to_string();
+    let mut package = app.clone();
+    let mut requirement = None;
+
+    for field in fields {
+        if let Some(req) = unquote(field) {
+            requirement = Some(req.to_string());
+            continue;
+        }
+        let Some((key, value)) = field.split_once(':') else { continue };
+        match key.trim() {
+            "hex" => package = value.trim().trim_start_matches(':').to_string(),
+            key if NON_HEX_OPTIONS.contains(&key) => return None,
+            _ => {}
+        }
+    }
+
+    Some(MixDep { app, package, requirement })
+}
+
+/// Hex packages from mix.lock, as (app, package, version)
+fn parse_mix_lock(content: &str) -> Vec<(String, String, String)> {
+    content
+        .lines()
+        .filter_map(|line| {
+            let (app, entry) = line.trim().strip_prefix('"')?.split_once("\":")?;
+            let mut fields = entry.trim().strip_prefix("{:hex,")?.split(',').map(str::trim);
+            let package = fields.next()?.strip_prefix(':')?;
+            let version = unquote(fields.next()?)?;
+            Some((app.to_string(), package.to_string(), version.to_string()))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MIX_EXS: &str = r#"defmodule MyApp.MixProject do
+  use Mix.Project
+
+  def project do
+    [app: :my_app, version: "0.1.0", deps: deps()]
+  end
+
+  defp deps do
+    [
+      {:phoenix, "~> 1.7.14"},
+      {:jason, "~> 1.2"}, # JSON, pinned loosely
+      {:credo, "~> 1.7", only: [:dev, :test], runtime: false},
+      {:plug_cowboy,
+       "== 2.7.1"},
+      {:dialyxir, ">= 0.0.0", only: :dev},
+      {:my_lib, path: "../my_lib"},
+      {:forked, git: "https://github.com/org/forked.git", tag: "v0.1.0"},
+      {:other, github: "org/other"},
+      {:yaml, "~> 2.0", hex: :yaml_elixir}
+    ]
+  end
+end
+"#;
+
+    const MIX_LOCK: &str = r#"%{
+  "castore": {:hex, :castore, "1.0.8", "dedcf20e", [:mix], [], "hexpm", "0b2b66d2"},
+  "forked": {:git, "https://github.com/org/forked.git", "a1b2c3", [tag: "v0.1.0"]},
+  "jason": {:hex, :jason, "1.4.4", "b9226785", [:mix], [{:decimal, "~> 1.0 or ~> 2.0", [hex: :decimal, repo: "hexpm", optional: true]}], "hexpm", "c5eb0cab"},
+  "phoenix": {:hex, :phoenix, "1.7.14", "a7d0b3f1", [:mix], [], "hexpm", "c7859bc5"},
+  "yaml": {:hex, :yaml_elixir, "2.11.0", "9e9ccd13", [:mix], [], "hexpm", "53cc28357"},
+}
+"#;
+
+    #[test]
+    fn test_parse_mix_exs() {
+        let deps = parse_mix_exs(MIX_EXS);
+        let names: Vec<_> = deps.iter().map(|d| d.package.as_str()).collect();
+        assert_eq!(
+            names,
+            vec!["phoenix", "jason", "credo", "plug_cowboy", "dialyxir", "yaml_elixir"]
+        );
+        assert_eq!(deps[2].requirement.as_deref(), Some("~> 1.7"));
+        assert_eq!(deps[5].app, "yaml");
+    }
+
+    #[test]
+    fn test_parse_mix_lock() {
+        let locked = parse_mix_lock(MIX_LOCK);
+        assert_eq!(locked.len(), 4);
+        assert_eq!(
+            locked[3],
+            ("yaml".to_string(), "yaml_elixir".to_string(), "2.11.0".to_string())
+        );
+    }
+
+    #[test]
+    fn test_resolve_with_lock() {
+        let deps = resolve(&parse_mix_exs(MIX_EXS), &parse_mix_lock(MIX_LOCK));
+        let find = |name: &str| deps.iter().find(|d| d.name == name);
+        assert!(find("phoenix").is_some_and(|d| d.version.as_deref() == Some("1.7.14")
+            && d.constraint.as_deref() == Some("~> 1.7.14")));
+        assert_eq!(find("yaml_elixir").and_then(|d| d.version.as_deref()), Some("2.11.0"));
+        // Not locked, but required exactly
+        assert_eq!(find("plug_cowboy").and_then(|d| d.version.as_deref()), Some("2.7.1"));
+        assert_eq!(find("credo").and_then(|d| d.version.as_deref()), None);
+        assert!(find("castore").is_some_and(|d| d.transitive));
+        assert!(find("forked").is_none());
+    }
+}
@@ -0,0 +1,161 @@
+//! Project file scanning - detects and parses dependency files
+
+mod actions;
+mod composer;
+mod docker;
+mod dotnet;
+mod gemfile;
+mod gomod;
+mod gradle;
+mod helm;
+mod hex;
+mod kubernetes;
+mod maven;
+mod mix;
+mod precommit;
+mod pubspec;
+mod rebar;
+mod requirements;
+mod swiftpm;
+mod terraform;
+mod toolchains;
+mod uv;
+
+use std::fs;
+
+pub struct ProjectInfo {
+    pub file: &'static str,
+    pub source: &'static str, // Which source to use: "cargo", "npm", "pip", "go"
+    pub packages: Vec<Dependency>,
+}
+
+/// A dependency declared in a project file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dependency {
+    pub name: String,
+    /// Pinned or locked version, compared against the registry as if installed
+    pub version: Option<String>,
+    /// Alternate registry to query instead of the source's default (e.g. a private index)
+    pub registry: Option<String>,
+    /// Only pulled in by other dependencies, not declared by the project itself
+    pub transitive: bool,
+    /// Source to query instead of the project's (e.g. the toolchain pin in go.mod)
+    pub source: Option<&'static str>,
+    /// Declared version requirement (e.g. `~> 1.7`); newer versions outside it
+    /// are noted rather than reported as outdated
+    pub constraint: Option<String>,
+    /// Where the reference was found (`file:line`), for files that may declare
+    /// the same package more than once
+    pub location: Option<String>,
+    /// Content digest the reference is pinned to (`sha256:...`), for tags that
+    /// can be republished
+    pub digest: Option<String>,
+}
+
+impl Dependency {
+    #[must_use]
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            version: None,
+            registry: None,
+            transitive: false,
+            source: None,
+            constraint: None,
+            location: None,
+            digest: None,
+        }
+    }
+}
+
+/// Scan current directory for project files: the first language project found,
+/// plus any container images, GitHub Actions, pre-commit hooks and runtime pins alongside it
+pub fn scan() -> Vec<ProjectInfo> {
+    let language = scan_cargo()
+        .or_else(scan_npm)
+        .or_else(uv::scan)
+        .or_else(scan_pyproject)
+        .or_else(requirements::scan)
+        .or_else(gomod::scan)
+        .or_else(gemfile::scan)
+        .or_else(mix::scan)
+        .or_else(rebar::scan)
+        .or_else(pubspec::scan)
+        .or_else(composer::scan)
+        .or_else(maven::scan)
+        .or_else(gradle::scan)
+        .or_else(dotnet::scan)
+        .or_else(swiftpm::scan)
+        .or_else(terraform::scan)
+        .or_else(helm::scan);
+    language
+        .into_iter()
+        .chain(docker::scan())
+        .chain(kubernetes::scan())
+        .chain(actions::scan())
+        .chain(precommit::scan())
+        .chain(toolchains::scan())
+        .collect()
+}
+
+fn scan_cargo() -> Option<ProjectInfo> {
+    let content = fs::read_to_string("Cargo.toml").ok()?;
+    let doc: toml::Value = toml::from_str(&content).ok()?;
+
+    let mut packages = Vec::new();
+
+    for section in ["dependencies", "dev-dependencies", "build-dependencies"] {
+        if let Some(deps) = doc.get(section).and_then(|d| d.as_table()) {
+            packages.extend(deps.keys().map(Dependency::new));
+        }
+    }
+
+    if packages.is_empty() {
+        return None;
+    }
+
+    Some(ProjectInfo { file: "Cargo.toml", source: "cargo", packages })
+}
+
+fn scan_npm() -> Option<ProjectInfo> {
+    let content = fs::read_to_string("package.json").ok()?;
+    let doc: serde_json::Value = serde_json::from_str(&content).ok()?;
+
+    let mut packages = Vec::new();
+
+    for section in ["dependencies", "devDependencies"] {
+        if let Some(deps) = doc.get(section).and_then(|d| d.as_object()) {
+            packages.extend(deps.keys().map(Dependency::new));
+        }
+    }
+
+    if packages.is_empty() {
+        return None;
+    }
+
+    Some(ProjectInfo { file: "package.json", source: "npm", packages })
+}
+
+fn scan_pyproject() -> Option<ProjectInfo> {
+    let content = fs::read_to_string("pyproject.toml").ok()?;
+    let doc: toml::Value = toml::from_str(&content).ok()?;
+
+    let deps = doc.get("project")?.get("dependencies")?.as_array()?;
+
+    let packages: Vec<Dependency> = deps
+        .iter()
+        .filter_map(|d| d.as_str())
+        .map(|s| {
+            // Parse "flask>=3.0" -> "flask"
+            Dependency::new(
+                s.split(|c: char| !c.is_alphanumeric() && c != '-' && c != '_').next().unwrap_or(s),
+            )
+        })
+        .collect();
+
+    if packages.is_empty() {
+        return None;
+    }
+
+    Some(ProjectInfo { file: "pyproject.toml", source: "pip", packages })
+}
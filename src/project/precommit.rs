@@ -0,0 +1,123 @@
+//! .pre-commit-config.yaml scanning: each hook repository's `rev`
+
+use super::{Dependency, ProjectInfo};
+use regex::Regex;
+use std::fs;
+use std::sync::LazyLock;
+
+const CONFIG: &str = ".pre-commit-config.yaml";
+
+static REPO: LazyLock<Regex> = LazyLock::new(|| {
+    #[allow(clippy::unwrap_used)]
+    Regex::new(r#"^\s*(?:-\s+)?repo:\s*["']?([^"'\s#]+)"#).unwrap()
+});
+
+/// `rev: v1.2.3`, or a frozen SHA with `# frozen: v1.2.3` after it
+static REV: LazyLock<Regex> = LazyLock::new(|| {
+    #[allow(clippy::unwrap_used)]
+    Regex::new(r#"^\s*(?:-\s+)?rev:\s*["']?([^"'\s#]+)["']?\s*(?:#\s*frozen:\s*(\S+))?"#).unwrap()
+});
+
+pub fn scan() -> Option<ProjectInfo> {
+    let content = fs::read_to_string(CONFIG).ok()?;
+    let packages = parse_config(&content);
+    if packages.is_empty() {
+        return None;
+    }
+
+    Some(ProjectInfo { file: CONFIG, source: "git", packages })
+}
+
+/// Hook repositories at their pinned revision. `local` and `meta` hooks live in
+/// the config itself and have no upstream
+fn parse_config(content: &str) -> Vec<Dependency> {
+    let mut packages = Vec::new();
+    let mut repo: Option<&str> = None;
+    for (line, text) in content.lines().enumerate() {
+        if let Some(caps) = REPO.captures(text) {
+            repo = caps.get(1).map(|m| m.as_str()).filter(|r| !matches!(*r, "local" | "meta"));
+        } else if let Some(caps) = REV.captures(text)
+            && let Some(url) = repo.take()
+        {
+            // A frozen SHA is compared by the tag in its comment
+            let rev = caps.get(2).or_else(|| caps.get(1)).map_or("", |m| m.as_str());
+            let is_sha = rev.len() >= 7 && rev.chars().all(|c| c.is_ascii_hexdigit());
+            let version = rev.strip_prefix('v').unwrap_or(rev);
+            let version = (!is_sha && version.starts_with(|c: char| c.is_ascii_digit()))
+                .then(|| version.to_string());
+            packages.push(Dependency {
+                version,
+                location: Some(format!("{CONFIG}:{}", line + 1)),
+                ..Dependency::new(url)
+            });
+        }
+    }
+    packages
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONFIG_YAML: &str = r"repos:
+  - repo: https://github.com/pre-commit/pre-commit-hooks
+    rev: v4.5.0
+    hooks:
+      - id: trailing-whitespace
+  - repo: https://github.com/psf/black
+    rev: 23.1.0
+    hooks:
+      - id: black
+  - repo: https://github.com/astral-sh/ruff-pre-commit
+    rev: 3d2b6b1b6a0e1c9b8a7f6e5d4c3b2a1908f7e6d5  # frozen: v0.1.9
+    hooks:
+      - id: ruff
+  - repo: https://gitlab.com/acme/hooks
+    rev: 'v1.2.0'
+    hooks:
+      - id: lint
+  - repo: local
+    hooks:
+      - id: pytest
+        entry: pytest
+  - repo: meta
+    hooks:
+      - id: check-hooks-apply
+";
+
+    #[test]
+    fn test_parse_config() {
+        let deps = parse_config(CONFIG_YAML);
+        let summary: Vec<_> = deps
+            .iter()
+            .map(|d| (d.name.as_str(), d.version.as_deref(), d.location.as_deref()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (
+                    "https://github.com/pre-commit/pre-commit-hooks",
+                    Some("4.5.0"),
+                    Some(".pre-commit-config.yaml:3")
+                ),
+                ("https://github.com/psf/black", Some("23.1.0"), Some(".pre-commit-config.yaml:7")),
+                (
+                    "https://github.com/astral-sh/ruff-pre-commit",
+                    Some("0.1.9"),
+                    Some(".pre-commit-config.yaml:11")
+                ),
+                (
+                    "https://gitlab.com/acme/hooks",
+                    Some("1.2.0"),
+                    Some(".pre-commit-config.yaml:15")
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unfrozen_sha_has_no_version() {
+        let deps = parse_config("repos:\n- repo: https://github.com/a/b\n  rev: 3d2b6b1b\n");
+        assert_eq!(deps[0].version, None);
+    }
+}
@@ -0,0 +1,236 @@
+//! pubspec.yaml and pubspec.lock scanning
+
+use super::{Dependency, ProjectInfo};
+use serde_yaml_ng::Value;
+use std::fs;
+
+/// Hosts that serve the public pub.dev repository
+const PUB_DEV_HOSTS: &[&str] = &["https://pub.dev", "https://pub.dartlang.org"];
+
+pub fn scan() -> Option<ProjectInfo> {
+    let content = fs::read_to_string("pubspec.yaml").ok()?;
+    let pubspec: Value = serde_yaml_ng::from_str(&content).ok()?;
+    let lock = fs::read_to_string("pubspec.lock")
+        .ok()
+        .and_then(|lock| serde_yaml_ng::from_str::<Value>(&lock).ok());
+
+    let packages = parse_pubspec(&pubspec, lock.as_ref());
+    if packages.is_empty() {
+        return None;
+    }
+
+    Some(ProjectInfo { file: "pubspec.yaml", source: "pub", packages })
+}
+
+fn parse_pubspec(pubspec: &Value, lock: Option<&Value>) -> Vec<Dependency> {
+    let mut packages: Vec<Dependency> = Vec::new();
+
+    // Overrides come last so they replace the constraint declared earlier
+    for section in ["dependencies", "dev_dependencies", "dependency_overrides"] {
+        let Some(deps) = pubspec.get(section).and_then(Value::as_mapping) else { continue };
+        for (name, spec) in deps {
+            let Some(name) = name.as_str() else { continue };
+            packages.retain(|d| d.name != name);
+            if let Some(constraint) = hosted_constraint(spec) {
+                packages.push(Dependency { constraint, ..Dependency::new(name) });
+            }
+        }
+    }
+
+    let locked = lock.map(locked_packages).unwrap_or_default();
+    for dep in &mut packages {
+        if let Some((_, version, _)) = locked.iter().find(|(name, _, _)| *name == dep.name) {
+            dep.version = Some(version.clone());
+        }
+    }
+    let transitive: Vec<Dependency> = locked
+        .iter()
+        .filter(|(name, _, direct)| !direct && !packages.iter().any(|d| &d.name == name))
+        .map(|(name, version, _)| Dependency {
+            version: Some(version.clone()),
+            transitive: true,
+            ..Dependency::new(name)
+        })
+        .collect();
+
+    if let Some(sdk) = pubspec.get("environment").and_then(|e| e.get("sdk")).and_then(Value::as_str)
+    {
+        packages.insert(
+            0,
+            Dependency {
+                source: Some("dart"),
+                constraint: Some(sdk.to_string()),
+                ..Dependency::new("dart")
+            },
+        );
+    }
+
+    packages.extend(transitive);
+    packages
+}
+
+/// The version constraint of a dependency served by pub.dev (`Some(None)` when
+/// unconstrained), or `None` for SDK, path, git and third-party hosted deps
+#[allow(clippy::option_option)]
+fn hosted_constraint(spec: &Value) -> Option<Option<String>> {
+    match spec {
+        Value::Null => Some(None),
+        Value::String(constraint) => Some(Some(constraint.clone())),
+        Value::Mapping(map) => {
+            if ["sdk", "path", "git"].iter().any(|key| map.contains_key(*key)) {
+                return None;
+            }
+            // `hosted:` is either the URL or `{name, url}`
+            let host = spec.get("hosted").map(|h| h.get("url").unwrap_or(h));
+            if host.is_some_and(|h| !h.as_str().is_some_and(is_pub_dev)) {
+                return None;
+            }
+            Some(spec.get("version").and_then(Value::as_str).map(String::from))
+        }
+        _ => None,
+    }
+}
+
+fn is_pub_dev(url: &str) -> bool {
+    PUB_DEV_HOSTS.contains(&url.trim_end_matches('/'))
+}
+
+/// pub.dev packages from pubspec.lock, as (name, version, direct)
+fn locked_packages(lock: &Value) -> Vec<(String, String, bool)> {
+    let Some(packages) = lock.get("packages").and_then(Value::as_mapping) else {
+        return Vec::new();
+    };
+    packages
+        .iter()
+        .filter_map(|(name, entry)| {
+            if entry.get("source")?.as_str()? != "hosted" {
+                return None;
+            }
+            let url = entry.get("description")?.get("url")?.as_str()?;
+            if !is_pub_dev(url) {
+                return None;
+            }
+            let direct = entry.get("dependency")?.as_str()?.starts_with("direct");
+            let version = entry.get("version")?.as_str()?;
+            Some((name.as_str()?.to_string(), version.to_string(), direct))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PUBSPEC: &str = r#"name: my_app
+environment:
+  sdk: ">=3.0.0 <4.0.0"
+  flutter: ">=3.10.0"
+
+dependencies:
+  flutter:
+    sdk: flutter
+  http: ^1.1.0
+  provider:
+  intl:
+    version: ^0.19.0
+    hosted: https://pub.dev
+  private_pkg:
+    hosted:
+      name: private_pkg
+      url: https://pub.example.com
+    version: ^1.0.0
+  local_pkg:
+    path: ../local_pkg
+  forked:
+    git:
+      url: https://github.com/org/forked.git
+
+dev_dependencies:
+  flutter_test:
+    sdk: flutter
+  lints: ^4.0.0
+
+dependency_overrides:
+  http: 1.2.0
+"#;
+
+    const LOCK: &str = r#"packages:
+  async:
+    dependency: transitive
+    description:
+      name: async
+      sha256: "947bfcf1"
+      url: "https://pub.dev"
+    source: hosted
+    version: "2.11.0"
+  flutter:
+    dependency: "direct main"
+    description: flutter
+    source: sdk
+    version: "0.0.0"
+  http:
+    dependency: "direct overridden"
+    description:
+      name: http
+      sha256: "b9c29a16"
+      url: "https://pub.dev"
+    source: hosted
+    version: "1.2.0"
+  lints:
+    dependency: "direct dev"
+    description:
+      name: lints
+      sha256: "3315600f"
+      url: "https://pub.dev"
+    source: hosted
+    version: "4.0.0"
+  private_dep:
+    dependency: transitive
+    description:
+      name: private_dep
+      sha256: "00000000"
+      url: "https://pub.example.com"
+    source: hosted
+    version: "0.1.0"
+sdks:
+  dart: ">=3.4.0 <4.0.0"
+"#;
+
+    fn parse(pubspec: &str, lock: Option<&str>) -> Vec<Dependency> {
+        let pubspec: Value = serde_yaml_ng::from_str(pubspec).unwrap_or(Value::Null);
+        let lock = lock.and_then(|l| serde_yaml_ng::from_str::<Value>(l).ok());
+        parse_pubspec(&pubspec, lock.as_ref())
+    }
+
+    fn summary(deps: &[Dependency]) -> Vec<(&str, Option<&str>, bool)> {
+        deps.iter().map(|d| (d.name.as_str(), d.version.as_deref(), d.transitive)).collect()
+    }
+
+    #[test]
+    fn test_parse_pubspec_with_lock() {
+        assert_eq!(
+            summary(&parse(PUBSPEC, Some(LOCK))),
+            vec![
+                ("dart", None, false),
+                ("provider", None, false),
+                ("intl", None, false),
+                ("lints", Some("4.0.0"), false),
+                ("http", Some("1.2.0"), false),
+                ("async", Some("2.11.0"), true),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_constraints_and_sdk() {
+        let deps = parse(PUBSPEC, None);
+        let find = |name: &str| deps.iter().find(|d| d.name == name);
+        // The override replaces the declared constraint
+        assert_eq!(find("http").and_then(|d| d.constraint.as_deref()), Some("1.2.0"));
+        assert_eq!(find("intl").and_then(|d| d.constraint.as_deref()), Some("^0.19.0"));
+        // The SDK constraint is checked as a requirement, not as a pinned version
+        assert!(find("dart").is_some_and(|d| d.source == Some("dart")
+            && d.version.is_none()
+            && d.constraint.as_deref() == Some(">=3.0.0 <4.0.0")));
+    }
+}
@@ -0,0 +1,177 @@
+//! rebar.config and rebar.lock scanning
+
+use super::hex::{enclosed, exact_requirement, split_terms, strip_comments, unquote};
+use super::{Dependency, ProjectInfo};
+use std::fs;
+
+pub fn scan() -> Option<ProjectInfo> {
+    let config = fs::read_to_string("rebar.config").ok()?;
+    let locked =
+        fs::read_to_string("rebar.lock").map(|lock| parse_rebar_lock(&lock)).unwrap_or_default();
+
+    let mut packages = parse_rebar_config(&config);
+    for dep in &mut packages {
+        if let Some((_, version, _)) = locked.iter().find(|(name, _, _)| *name == dep.name) {
+            dep.version = Some(version.clone());
+        }
+    }
+    // Level 0 entries are the project's own deps; deeper ones came along with them
+    let transitive: Vec<Dependency> = locked
+        .iter()
+        .filter(|(name, _, level)| *level > 0 && !packages.iter().any(|d| &d.name == name))
+        .map(|(name, version, _)| Dependency {
+            version: Some(version.clone()),
+            transitive: true,
+            ..Dependency::new(name)
+        })
+        .collect();
+    packages.extend(transitive);
+
+    if packages.is_empty() {
+        return None;
+    }
+
+    Some(ProjectInfo { file: "rebar.config", source: "hex", packages })
+}
+
+/// Hex deps from every `{deps, [...]}` list, including those under profiles
+fn parse_rebar_config(content: &str) -> Vec<Dependency> {
+    let content = strip_comments(content, '%');
+    let mut packages: Vec<Dependency> = Vec::new();
+
+    for (start, _) in content.match_indices("{deps,") {
+        let rest = &content[start + "{deps,".len()..];
+        let Some(list) = rest.find('[').and_then(|i| enclosed(&rest[i..])) else { continue };
+        for dep in split_terms(list).into_iter().filter_map(parse_rebar_dep) {
+            if !packages.iter().any(|d| d.name == dep.name) {
+                packages.push(dep);
+            }
+        }
+    }
+    packages
+}
+
+/// Parse `name`, `{name, "req"}`, `{name, "req", {pkg, real}}` or `{name, {pkg, real}}`;
+/// None for git and other source deps
+fn parse_rebar_dep(term: &str) -> Option<Dependency> {
+    if !term.starts_with('{') {
+        return is_atom(term).then(|| Dependency::new(term));
+    }
+
+    let mut fields = split_terms(enclosed(term)?).into_iter();
+    let name = fields.next().filter(|n| is_atom(n))?;
+    let mut package = name;
+    let mut requirement = None;
+
+    for field in fields {
+        if let Some(req) = unquote(field) {
+            requirement = Some(req);
+        } else if let [kind, real] = split_terms(enclosed(field)?)[..]
+            && kind == "pkg"
+        {
+            package = real;
+        } else {
+            return None;
+        }
+    }
+
+    Some(Dependency {
+        version: requirement.and_then(exact_requirement),
+        constraint: requirement.map(String::from),
+        ..Dependency::new(package)
+    })
+}
+
+fn is_atom(s: &str) -> bool {
+    s.starts_with(|c: char| c.is_ascii_lowercase())
+        && s.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Hex packages from rebar.lock, as (package, version, level)
+fn parse_rebar_lock(content: &str) -> Vec<(String, String, u32)> {
+    // {<<"cowlib">>,{pkg,<<"cowlib">>,<<"2.12.1">>},1}
+    let compact: String = content.chars().filter(|c| !c.is_whitespace()).collect();
+    compact
+        .split("{<<\"")
+        .skip(1)
+        .filter_map(|entry| {
+            let (_, rest) = entry.split_once("\">>,{pkg,<<\"")?;
+            let (package, rest) = rest.split_once("\">>,<<\"")?;
+            let (version, rest) = rest.split_once("\">>")?;
+            let level = rest.split_once("},")?.1.split('}').next()?.parse().ok()?;
+            Some((package.to_string(), version.to_string(), level))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REBAR_CONFIG: &str = r#"{erl_opts, [debug_info]}.
+
+{deps, [
+    cowboy, % latest from hex
+    {jsx, "3.1.0"},
+    {lager, "~> 3.9"},
+    {rebar, {git, "https://github.com/erlang/rebar3.git", {tag, "3.0.0"}}},
+    {my_eredis, "1.7.1", {pkg, eredis}}
+]}.
+
+{profiles, [
+    {test, [{deps, [meck, {proper, "1.4.0"}]}]}
+]}.
+"#;
+
+    const REBAR_LOCK: &str = r#"{"1.2.0",
+[{<<"cowboy">>,{pkg,<<"cowboy">>,<<"2.12.0">>},0},
+ {<<"cowlib">>,{pkg,<<"cowlib">>,<<"2.13.0">>},1},
+ {<<"jsx">>,{pkg,<<"jsx">>,<<"3.1.0">>},0},
+ {<<"rebar">>,{git,"https://github.com/erlang/rebar3.git",{ref,"abc"}},0}]}.
+[
+{pkg_hash,[
+ {<<"cowboy">>, <<"F3DDF1A0">>},
+ {<<"cowlib">>, <<"DB8F7505">>}]}
+].
+"#;
+
+    fn summary(deps: &[Dependency]) -> Vec<(&str, Option<&str>)> {
+        deps.iter().map(|d| (d.name.as_str(), d.version.as_deref())).collect()
+    }
+
+    #[test]
+    fn test_parse_rebar_config() {
+        let deps = parse_rebar_config(REBAR_CONFIG);
+        assert_eq!(
+            summary(&deps),
+            vec![
+                ("cowboy", None),
+                ("jsx", Some("3.1.0")),
+                ("lager", None),
+                ("eredis", Some("1.7.1")),
+                ("meck", None),
+                ("proper", Some("1.4.0")),
+            ]
+        );
+        assert_eq!(deps[2].constraint.as_deref(), Some("~> 3.9"));
+    }
+
+    #[test]
+    fn test_parse_rebar_lock() {
+        assert_eq!(
+            parse_rebar_lock(REBAR_LOCK),
+            vec![
+                ("cowboy".to_string(), "2.12.0".to_string(), 0),
+                ("cowlib".to_string(), "2.13.0".to_string(), 1),
+                ("jsx".to_string(), "3.1.0".to_string(), 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_rebar_dep() {
+        assert!(parse_rebar_dep("{rebar, {git, \"url\", {branch, \"main\"}}}").is_none());
+        assert!(parse_rebar_dep("\"not_an_atom\"").is_none());
+        assert_eq!(parse_rebar_dep("cowboy").map(|d| d.name), Some("cowboy".to_string()));
+    }
+}
@@ -0,0 +1,238 @@
+//! requirements.txt and pip-tools (`.in`) scanning
+
+use super::{Dependency, ProjectInfo};
+use crate::sources::normalize_name;
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Requirement files as (compiled, source) pairs. A pip-compile `.txt` output
+/// carries the pins, so it wins over the `.in` file it was generated from.
+const FILES: &[(&str, &str)] =
+    &[("requirements.txt", "requirements.in"), ("requirements-dev.txt", "requirements-dev.in")];
+
+#[derive(Debug, PartialEq, Eq)]
+enum Line {
+    Requirement(Dependency),
+    Include(String),
+    Constraint(String),
+    IndexUrl(String),
+}
+
+#[derive(Default)]
+struct Collected {
+    packages: Vec<Dependency>,
+    constraints: Vec<Dependency>,
+    index_url: Option<String>,
+    visited: HashSet<PathBuf>,
+}
+
+pub fn scan() -> Option<ProjectInfo> {
+    let mut collected = Collected::default();
+    let mut file = None;
+
+    for (compiled, source) in FILES {
+        let name = if Path::new(compiled).exists() { compiled } else { source };
+        if collect(Path::new(name), false, &mut collected) {
+            file.get_or_insert(*name);
+        }
+    }
+
+    let Collected { mut packages, constraints, index_url, .. } = collected;
+
+    // Later files and includes may repeat a package; the first declaration wins
+    let mut seen = HashSet::new();
+    packages.retain(|d| seen.insert(normalize_name(&d.name)));
+
+    for dep in &mut packages {
+        if dep.version.is_none() {
+            let name = normalize_name(&dep.name);
+            dep.version = constraints
+                .iter()
+                .find(|c| c.version.is_some() && normalize_name(&c.name) == name)
+                .and_then(|c| c.version.clone());
+        }
+        dep.registry.clone_from(&index_url);
+    }
+
+    if packages.is_empty() {
+        return None;
+    }
+
+    Some(ProjectInfo { file: file?, source: "pypi", packages })
+}
+
+/// Read a requirements file and follow its `-r`/`-c` includes.
+/// Returns false if the file could not be read.
+fn collect(path: &Path, constraint: bool, out: &mut Collected) -> bool {
+    let key = fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+    if !out.visited.insert(key) {
+        return true; // Include cycle, already read
+    }
+    let Ok(content) = fs::read_to_string(path) else { return false };
+    let base = path.parent().unwrap_or_else(|| Path::new(""));
+
+    for line in parse_requirements(&content) {
+        match line {
+            Line::Requirement(dep) if constraint => out.constraints.push(dep),
+            Line::Requirement(dep) => out.packages.push(dep),
+            Line::Include(file) => {
+                collect(&base.join(file), constraint, out);
+            }
+            Line::Constraint(file) => {
+                collect(&base.join(file), true, out);
+            }
+            Line::IndexUrl(url) => {
+                out.index_url.get_or_insert(url);
+            }
+        }
+    }
+    true
+}
+
+fn parse_requirements(content: &str) -> Vec<Line> {
+    // Join backslash continuations (common with --hash lines from pip-compile)
+    let joined = content.replace("\\\r\n", " ").replace("\\\n", " ");
+    joined.lines().filter_map(parse_line).collect()
+}
+
+fn parse_line(line: &str) -> Option<Line> {
+    // Comments start a line or follow whitespace ("pkg==1.0  # via foo")
+    let line = line.find(" #").or_else(|| line.find("\t#")).map_or(line, |i| &line[..i]).trim();
+    if line.is_empty() || line.starts_with('#') {
+        return None;
+    }
+
+    if line.starts_with('-') {
+        return parse_option(line);
+    }
+
+    // Direct references, VCS URLs and local paths have no registry version
+    if line.contains("://")
+        || line.contains(" @ ")
+        || line.starts_with('.')
+        || line.starts_with('/')
+    {
+        return None;
+    }
+
+    // Drop trailing per-requirement options (--hash) and environment markers
+    let spec = line.split(" --").next().unwrap_or(line);
+    let spec = spec.split(';').next().unwrap_or(spec).trim();
+
+    let name_end = spec
+        .find(|c: char| !c.is_ascii_alphanumeric() && !matches!(c, '-' | '_' | '.'))
+        .unwrap_or(spec.len());
+    let name = &spec[..name_end];
+    if name.is_empty() {
+        return None;
+    }
+
+    // Skip extras ("requests[socks]==2.31.0")
+    let rest = spec[name_end..].trim_start();
+    let rest = rest
+        .strip_prefix('[')
+        .map_or(rest, |r| r.split_once(']').map_or("", |(_, r)| r).trim_start());
+
+    Some(Line::Requirement(Dependency { version: pinned_version(rest), ..Dependency::new(name) }))
+}
+
+fn parse_option(line: &str) -> Option<Line> {
+    let (flag, value) = match line.split_once(|c: char| c == '=' || c.is_whitespace()) {
+        Some((f, v)) => (f, v.trim().to_string()),
+        None => (line, String::new()),
+    };
+    if value.is_empty() {
+        return None;
+    }
+    match flag {
+        "-r" | "--requirement" => Some(Line::Include(value)),
+        "-c" | "--constraint" => Some(Line::Constraint(value)),
+        "-i" | "--index-url" => Some(Line::IndexUrl(value)),
+        // -e/--editable, --extra-index-url, --find-links, ... have nothing to check
+        _ => None,
+    }
+}
+
+/// Exact `==`/`===` pins are treated as the installed version
+fn pinned_version(spec: &str) -> Option<String> {
+    let version = spec.strip_prefix("===").or_else(|| spec.strip_prefix("=="))?.trim();
+    if version.is_empty() || version.contains([',', '*', '<', '>', '!', '~']) {
+        return None;
+    }
+    Some(version.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn req(name: &str, version: Option<&str>) -> Line {
+        Line::Requirement(Dependency {
+            version: version.map(String::from),
+            ..Dependency::new(name)
+        })
+    }
+
+    #[test]
+    fn test_parse_pinned_and_ranges() {
+        let content = "requests==2.31.0\nflask>=3.0\nDjango ~= 4.2\nnumpy===1.26.4\n";
+        assert_eq!(
+            parse_requirements(content),
+            vec![
+                req("requests", Some("2.31.0")),
+                req("flask", None),
+                req("Django", None),
+                req("numpy", Some("1.26.4")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_markers_extras_and_comments() {
+        let content = "# comment\n\nrequests[socks]==2.31.0 ; python_version >= \"3.8\"  # via app\n\
+                       importlib-metadata==7.0.0; python_version < \"3.10\"\n";
+        assert_eq!(
+            parse_requirements(content),
+            vec![req("requests", Some("2.31.0")), req("importlib-metadata", Some("7.0.0"))]
+        );
+    }
+
+    #[test]
+    fn test_parse_hashes_with_continuations() {
+        let content = "certifi==2024.2.2 \\\n    --hash=sha256:abc \\\n    --hash=sha256:def\n";
+        assert_eq!(parse_requirements(content), vec![req("certifi", Some("2024.2.2"))]);
+    }
+
+    #[test]
+    fn test_parse_includes_and_index() {
+        let content = "-r base.txt\n--requirement=dev.txt\n-c constraints.txt\n\
+                       --index-url https://pypi.example.com/simple\n--extra-index-url https://x\n";
+        assert_eq!(
+            parse_requirements(content),
+            vec![
+                Line::Include("base.txt".to_string()),
+                Line::Include("dev.txt".to_string()),
+                Line::Constraint("constraints.txt".to_string()),
+                Line::IndexUrl("https://pypi.example.com/simple".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_skips_editable_and_vcs() {
+        let content = "-e .\n-e git+https://github.com/org/repo.git#egg=repo\n\
+                       git+https://github.com/org/other.git\nmylib @ https://example.com/mylib.whl\n\
+                       ./local/pkg\n";
+        assert!(parse_requirements(content).is_empty());
+    }
+
+    #[test]
+    fn test_pinned_version() {
+        assert_eq!(pinned_version("==1.0"), Some("1.0".to_string()));
+        assert_eq!(pinned_version("== 1.0"), Some("1.0".to_string()));
+        assert_eq!(pinned_version("==1.*"), None);
+        assert_eq!(pinned_version(">=1.0,<2"), None);
+        assert_eq!(pinned_version(""), None);
+    }
+}
@@ -0,0 +1,291 @@
+//! Package.swift and Package.resolved scanning
+
+use super::{Dependency, ProjectInfo};
+use regex::Regex;
+use serde_json::Value;
+use std::fs;
+use std::sync::LazyLock;
+
+static URL: LazyLock<Regex> = LazyLock::new(|| {
+    #[allow(clippy::unwrap_used)]
+    Regex::new(r#"url:\s*"([^"]+)""#).unwrap()
+});
+
+/// Version requirement forms, as (pattern, requirement built from its captures)
+static REQUIREMENTS: LazyLock<Vec<(Regex, Requirement)>> = LazyLock::new(|| {
+    #[allow(clippy::unwrap_used)]
+    let re = |pattern: &str| Regex::new(pattern).unwrap();
+    vec![
+        (re(r#"upToNextMinor\(\s*from:\s*"([^"]+)""#), |c| up_to_next(&c[1], 1)),
+        (re(r#"(?:upToNextMajor\(\s*)?from:\s*"([^"]+)""#), |c| up_to_next(&c[1], 0)),
+        (re(r#"\.?exact(?::\s*|\(\s*)"([^"]+)""#), |c| Some(format!("== {}", &c[1]))),
+        (re(r#""([^"]+)"\s*\.\.<\s*"([^"]+)""#), |c| Some(format!(">= {}, < {}", &c[1], &c[2]))),
+        (re(r#""([^"]+)"\s*\.\.\.\s*"([^"]+)""#), |c| Some(format!(">= {}, <= {}", &c[1], &c[2]))),
+    ]
+});
+
+type Requirement = fn(&regex::Captures) -> Option<String>;
+
+/// `from` up to the next release of the component at `index` (0 for major, 1 for
+/// minor). Spelled out rather than as `^`/`~>`, since the Swift package manager
+/// doesn't treat 0.x specially: `from: "0.5.0"` allows everything below 1.0.0
+fn up_to_next(from: &str, index: usize) -> Option<String> {
+    let core = from.split(['-', '+']).next().unwrap_or(from);
+    let mut parts = core.split('.').map(|p| p.parse::<u64>().ok()).collect::<Option<Vec<_>>>()?;
+    parts.resize(3, 0);
+    parts[index] += 1;
+    parts[index + 1..].iter_mut().for_each(|p| *p = 0);
+    let upper = parts.iter().map(u64::to_string).collect::<Vec<_>>().join(".");
+    Some(format!(">= {from}, < {upper}"))
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Package {
+    url: String,
+    /// Requirement in `matches_requirement` syntax; None for branch/revision pins
+    requirement: Option<String>,
+    /// Lowest version the requirement allows
+    minimum: Option<String>,
+}
+
+pub fn scan() -> Option<ProjectInfo> {
+    let manifest = fs::read_to_string("Package.swift").ok()?;
+    let pins = fs::read_to_string("Package.resolved")
+        .ok()
+        .and_then(|r| serde_json::from_str::<Value>(&r).ok())
+        .map(|r| parse_resolved(&r))
+        .unwrap_or_default();
+
+    let packages = resolve(&parse_manifest(&manifest), &pins);
+    if packages.is_empty() {
+        return None;
+    }
+
+    Some(ProjectInfo { file: "Package.swift", source: "swift", packages })
+}
+
+/// Declared packages at their pinned version; pins the manifest doesn't
+/// mention are transitive
+fn resolve(declared: &[Package], pins: &[(String, String)]) -> Vec<Dependency> {
+    let pinned = |url: &str| pins.iter().find(|(u, _)| *u == normalize_url(url)).map(|(_, v)| v);
+
+    let mut packages: Vec<Dependency> = declared
+        .iter()
+        .map(|p| Dependency {
+            version: pinned(&p.url).cloned().or_else(|| p.minimum.clone()),
+            constraint: p.requirement.clone(),
+            ..Dependency::new(normalize_url(&p.url))
+        })
+        .collect();
+
+    packages.extend(
+        pins.iter().filter(|(url, _)| !declared.iter().any(|p| normalize_url(&p.url) == *url)).map(
+            |(url, version)| Dependency {
+                version: Some(version.clone()),
+                transitive: true,
+                ..Dependency::new(url)
+            },
+        ),
+    );
+    packages
+}
+
+/// `.package(url: ..., <requirement>)` entries; local `path:` and registry `id:`
+/// packages are skipped
+fn parse_manifest(content: &str) -> Vec<Package> {
+    let content: String = content.lines().map(strip_comment).collect::<Vec<_>>().join("\n");
+
+    let mut packages = Vec::new();
+    let mut rest = content.as_str();
+    while let Some(start) = rest.find(".package(") {
+        let args = &rest[start + ".package(".len()..];
+        let end = closing_paren(args).unwrap_or(args.len());
+        let (call, remaining) = args.split_at(end);
+        rest = remaining;
+
+        let Some(url) = URL.captures(call).map(|c| c[1].to_string()) else { continue };
+        let found = REQUIREMENTS.iter().find_map(|(re, requirement)| {
+            let caps = re.captures(call)?;
+            Some((requirement(&caps)?, caps[1].to_string()))
+        });
+        let (requirement, minimum) = found.unzip();
+        packages.push(Package { url, requirement, minimum });
+    }
+    packages
+}
+
+/// A line without its `//` comment, leaving `//` inside string literals (URLs) alone
+fn strip_comment(line: &str) -> &str {
+    let mut in_string = false;
+    for (i, c) in line.char_indices() {
+        match c {
+            '"' => in_string = !in_string,
+            '/' if !in_string && line[i..].starts_with("//") => return &line[..i],
+            _ => {}
+        }
+    }
+    line
+}
+
+/// Index of the parenthesis closing an argument list that's already been opened
+fn closing_paren(args: &str) -> Option<usize> {
+    let mut depth = 1;
+    let mut in_string = false;
+    for (i, c) in args.char_indices() {
+        match c {
+            '"' => in_string = !in_string,
+            '(' if !in_string => depth += 1,
+            ')' if !in_string => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Pinned (normalized URL, version) pairs from any Package.resolved format:
+/// v1 nests pins under `object` with `repositoryURL`; v2 and v3 use `location`
+fn parse_resolved(resolved: &Value) -> Vec<(String, String)> {
+    let pins = resolved
+        .get("pins")
+        .or_else(|| resolved.get("object")?.get("pins"))
+        .and_then(Value::as_array);
+
+    pins.into_iter()
+        .flatten()
+        .filter_map(|pin| {
+            let url = pin.get("location").or_else(|| pin.get("repositoryURL"))?.as_str()?;
+            let version = pin.get("state")?.get("version")?.as_str()?;
+            Some((normalize_url(url), version.to_string()))
+        })
+        .collect()
+}
+
+/// Repository URLs compare without scheme, case or `.git` suffix
+fn normalize_url(url: &str) -> String {
+    let url = url.split_once("://").map_or(url, |(_, rest)| rest);
+    url.trim_end_matches('/').trim_end_matches(".git").to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MANIFEST: &str = r#"// swift-tools-version:5.9
+import PackageDescription
+
+let package = Package(
+    name: "MyTool",
+    dependencies: [
+        .package(url: "https://github.com/apple/swift-argument-parser", from: "1.2.0"),
+        .package(url: "https://github.com/vapor/vapor.git", .upToNextMajor(from: "4.89.0")),
+        .package(url: "https://github.com/apple/swift-log.git", .upToNextMinor(from: "1.5.0")),
+        .package(url: "https://github.com/pointfreeco/swift-snapshot-testing", exact: "1.15.1"),
+        .package(
+            url: "https://github.com/apple/swift-nio.git",
+            "2.60.0"..<"3.0.0"
+        ),
+        .package(url: "https://github.com/org/unstable", branch: "main"),
+        // .package(url: "https://github.com/org/commented", from: "1.0.0"),
+        .package(path: "../LocalKit"),
+    ],
+    targets: [.executableTarget(name: "MyTool")]
+)
+"#;
+
+    #[test]
+    fn test_parse_manifest() {
+        let packages = parse_manifest(MANIFEST);
+        let summary: Vec<_> = packages
+            .iter()
+            .map(|p| (p.url.as_str(), p.requirement.as_deref(), p.minimum.as_deref()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (
+                    "https://github.com/apple/swift-argument-parser",
+                    Some(">= 1.2.0, < 2.0.0"),
+                    Some("1.2.0")
+                ),
+                ("https://github.com/vapor/vapor.git", Some(">= 4.89.0, < 5.0.0"), Some("4.89.0")),
+                (
+                    "https://github.com/apple/swift-log.git",
+                    Some(">= 1.5.0, < 1.6.0"),
+                    Some("1.5.0")
+                ),
+                (
+                    "https://github.com/pointfreeco/swift-snapshot-testing",
+                    Some("== 1.15.1"),
+                    Some("1.15.1")
+                ),
+                (
+                    "https://github.com/apple/swift-nio.git",
+                    Some(">= 2.60.0, < 3.0.0"),
+                    Some("2.60.0")
+                ),
+                ("https://github.com/org/unstable", None, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_up_to_next_zero_major() {
+        // Unlike a caret requirement, 0.x still runs up to the next major
+        let packages = parse_manifest(
+            r#".package(url: "https://github.com/org/early", from: "0.5.2"),
+               .package(url: "https://github.com/org/minor", .upToNextMinor(from: "0.5.2")),"#,
+        );
+        let requirements: Vec<_> = packages.iter().map(|p| p.requirement.as_deref()).collect();
+        assert_eq!(requirements, vec![Some(">= 0.5.2, < 1.0.0"), Some(">= 0.5.2, < 0.6.0")]);
+        assert_eq!(crate::sources::matches_requirement("0.9.0", ">= 0.5.2, < 1.0.0"), Some(true));
+        assert_eq!(up_to_next("1.0.0-beta.1", 0).as_deref(), Some(">= 1.0.0-beta.1, < 2.0.0"));
+        assert_eq!(up_to_next("main", 0), None);
+    }
+
+    #[test]
+    fn test_parse_resolved_formats() {
+        let v1 = r#"{"object":{"pins":[{"package":"swift-argument-parser",
+            "repositoryURL":"https://github.com/apple/swift-argument-parser",
+            "state":{"branch":null,"revision":"abc","version":"1.2.3"}}]},"version":1}"#;
+        let v2 = r#"{"pins":[{"identity":"swift-argument-parser","kind":"remoteSourceControl",
+            "location":"https://github.com/apple/swift-argument-parser",
+            "state":{"revision":"abc","version":"1.2.3"}}],"version":2}"#;
+        let v3 = r#"{"originHash":"f00","pins":[{"identity":"swift-argument-parser",
+            "kind":"remoteSourceControl","location":"https://github.com/apple/swift-argument-parser.git",
+            "state":{"revision":"abc","version":"1.2.3"}},
+            {"identity":"unstable","kind":"remoteSourceControl","location":"https://github.com/org/unstable",
+            "state":{"branch":"main","revision":"def"}}],"version":3}"#;
+
+        let expected =
+            vec![("github.com/apple/swift-argument-parser".to_string(), "1.2.3".to_string())];
+        for json in [v1, v2, v3] {
+            let resolved: Value = serde_json::from_str(json).unwrap_or_default();
+            assert_eq!(parse_resolved(&resolved), expected);
+        }
+    }
+
+    #[test]
+    fn test_resolve_with_pins() {
+        let pins = vec![
+            ("github.com/vapor/vapor".to_string(), "4.92.1".to_string()),
+            ("github.com/apple/swift-nio-ssl".to_string(), "2.26.0".to_string()),
+        ];
+        let deps = resolve(&parse_manifest(MANIFEST), &pins);
+        let find = |name: &str| deps.iter().find(|d| d.name == name);
+        assert_eq!(
+            find("github.com/vapor/vapor").and_then(|d| d.version.as_deref()),
+            Some("4.92.1")
+        );
+        // Unpinned: the requirement's lower bound stands in
+        assert_eq!(
+            find("github.com/apple/swift-log").and_then(|d| d.version.as_deref()),
+            Some("1.5.0")
+        );
+        assert!(find("github.com/apple/swift-nio-ssl").is_some_and(|d| d.transitive));
+    }
+}
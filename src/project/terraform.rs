@@ -0,0 +1,490 @@
+//! Terraform/OpenTofu scanning: `required_providers`, registry `module` sources
+//! and `.terraform.lock.hcl`
+
+use super::{Dependency, ProjectInfo};
+use regex::Regex;
+use serde_json::Value;
+use std::fs;
+use std::path::Path;
+use std::sync::LazyLock;
+
+const DEFAULT_REGISTRY: &str = "registry.terraform.io";
+
+/// `name = { ... }` or the legacy `name = "constraint"` inside `required_providers`
+static PROVIDER: LazyLock<Regex> = LazyLock::new(|| {
+    #[allow(clippy::unwrap_used)]
+    Regex::new(r#"(?m)^\s*([\w-]+)\s*=\s*(\{[^}]*\}|"[^"]*")"#).unwrap()
+});
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Declared {
+    /// Registry address without the default host: `hashicorp/aws`, `terraform-aws-modules/vpc/aws`
+    address: String,
+    /// Registry host when it isn't registry.terraform.io
+    host: Option<String>,
+    constraint: Option<String>,
+}
+
+pub fn scan() -> Option<ProjectInfo> {
+    let mut files: Vec<String> = fs::read_dir(".")
+        .ok()?
+        .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+        .filter(|name| Path::new(name).extension().is_some_and(|e| e.eq_ignore_ascii_case("tf")))
+        .collect();
+    files.sort();
+    let content: String = files.iter().filter_map(|f| fs::read_to_string(f).ok()).collect();
+    let content = strip_comments(&content);
+
+    let providers = parse_required_providers(&content);
+    let modules = parse_modules(&content);
+    let locked =
+        fs::read_to_string(".terraform.lock.hcl").map(|l| parse_lock(&l)).unwrap_or_default();
+    let installed = fs::read_to_string(".terraform/modules/modules.json")
+        .ok()
+        .and_then(|m| serde_json::from_str::<Value>(&m).ok())
+        .map(|m| installed_modules(&m))
+        .unwrap_or_default();
+
+    let packages = resolve(&providers, &modules, &locked, &installed);
+    if packages.is_empty() {
+        return None;
+    }
+
+    Some(ProjectInfo { file: "*.tf", source: "terraform", packages })
+}
+
+/// Declared providers at their locked version and modules at their installed
+/// version, or an exact constraint's. Locked providers that nothing declares
+/// (pulled in by modules or implied) are transitive
+fn resolve(
+    providers: &[Declared],
+    modules: &[(String, Declared)],
+    locked: &[(Declared, String)],
+    installed: &[(String, String)],
+) -> Vec<Dependency> {
+    let dependency = |declared: &Declared, version: Option<String>| Dependency {
+        version: version.or_else(|| declared.constraint.as_deref().and_then(exact_version)),
+        registry: declared.host.clone(),
+        constraint: declared.constraint.clone(),
+        ..Dependency::new(&declared.address)
+    };
+
+    let mut packages: Vec<Dependency> = Vec::new();
+    for provider in providers {
+        let lock = locked.iter().find(|(l, _)| l.address == provider.address);
+        // The lock file records which registry (Terraform or OpenTofu) installed it
+        let host = lock.map_or_else(|| provider.host.clone(), |(l, _)| l.host.clone());
+        let declared = Declared { host, ..provider.clone() };
+        packages.push(dependency(&declared, lock.map(|(_, v)| v.clone())));
+    }
+    for (name, module) in modules {
+        let version = installed.iter().find(|(key, _)| key == name).map(|(_, v)| v.clone());
+        if !packages.iter().any(|d| d.name == module.address) {
+            packages.push(dependency(module, version));
+        }
+    }
+    for (lock, version) in locked {
+        if !packages.iter().any(|d| d.name == lock.address) {
+            packages
+                .push(Dependency { transitive: true, ..dependency(lock, Some(version.clone())) });
+        }
+    }
+    packages
+}
+
+/// `required_providers` entries from every `terraform` block; a bare constraint or
+/// missing `source` means `hashicorp/<name>`
+fn parse_required_providers(content: &str) -> Vec<Declared> {
+    let mut providers = Vec::new();
+    for (_, terraform) in blocks(content, "terraform") {
+        for (_, body) in blocks(terraform, "required_providers") {
+            for caps in PROVIDER.captures_iter(body) {
+                let (name, value) = (&caps[1], &caps[2]);
+                let (source, constraint) = value.strip_prefix('{').map_or_else(
+                    || (None, Some(value.trim_matches('"').to_string())),
+                    |object| {
+                        let object = object.trim_end_matches('}');
+                        (attribute(object, "source"), attribute(object, "version"))
+                    },
+                );
+                let source = source.unwrap_or_else(|| format!("hashicorp/{name}"));
+                providers.push(address(&source, constraint));
+            }
+        }
+    }
+    providers
+}
+
+/// `module` blocks with a registry source, keyed by module name. Local paths,
+/// VCS, HTTP and bucket sources have no registry versions
+fn parse_modules(content: &str) -> Vec<(String, Declared)> {
+    blocks(content, "module")
+        .into_iter()
+        .filter_map(|(labels, body)| {
+            let source = attribute(body, "source")?;
+            let source = source.split("//").next().unwrap_or(&source);
+            let parts = source.split('/').count();
+            let is_registry = !source.starts_with('.')
+                && !source.contains("::")
+                && !source.contains(':')
+                && !source.starts_with("github.com/")
+                && !source.starts_with("bitbucket.org/")
+                && (parts == 3 || (parts == 4 && source.split('/').next()?.contains('.')));
+            if !is_registry {
+                return None;
+            }
+            Some((labels.first()?.clone(), address(source, attribute(body, "version"))))
+        })
+        .collect()
+}
+
+/// `provider "registry.terraform.io/hashicorp/aws" { version = "5.31.0" ... }`
+fn parse_lock(content: &str) -> Vec<(Declared, String)> {
+    let content = strip_comments(content);
+    blocks(&content, "provider")
+        .into_iter()
+        .filter_map(|(labels, body)| {
+            let version = attribute(body, "version")?;
+            Some((address(labels.first()?, attribute(body, "constraints")), version))
+        })
+        .collect()
+}
+
+/// Resolved module versions from `terraform init`'s `.terraform/modules/modules.json`
+fn installed_modules(manifest: &Value) -> Vec<(String, String)> {
+    let modules = manifest.get("Modules").and_then(Value::as_array).into_iter().flatten();
+    modules
+        .filter_map(|m| {
+            let key = m.get("Key")?.as_str()?;
+            let version = m.get("Version")?.as_str()?;
+            (!key.is_empty() && !key.contains('.')).then(|| (key.to_string(), version.to_string()))
+        })
+        .collect()
+}
+
+/// Split a registry address into its host (unless it's the default) and the rest
+fn address(source: &str, constraint: Option<String>) -> Declared {
+    let (host, address) = match source.split_once('/') {
+        Some((host, rest)) if host.contains('.') => (Some(host), rest),
+        _ => (None, source),
+    };
+    Declared {
+        address: address.to_lowercase(),
+        host: host.filter(|h| !h.eq_ignore_ascii_case(DEFAULT_REGISTRY)).map(String::from),
+        constraint: constraint.filter(|c| !c.trim().is_empty()),
+    }
+}
+
+/// Without a lock, only an exact constraint (`5.1.2` or `= 5.1.2`) says which version
+/// `terraform init` installs; anything looser is checked as a requirement alone
+fn exact_version(constraint: &str) -> Option<String> {
+    let version = constraint.strip_prefix('=').unwrap_or(constraint).trim();
+    version
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '+'))
+        .then(|| version.to_string())
+        .filter(|v| v.starts_with(|c: char| c.is_ascii_digit()))
+}
+
+/// Blocks of `keyword` at the top level of `content`, with their quoted labels
+/// and the text between the braces
+fn blocks<'a>(content: &'a str, keyword: &str) -> Vec<(Vec<String>, &'a str)> {
+    let mut found = Vec::new();
+    let mut depth = 0;
+    let mut in_string = false;
+    let mut line_start = true;
+    let mut i = 0;
+    let bytes = content.as_bytes();
+    while i < bytes.len() {
+        let c = bytes[i];
+        if in_string {
+            if c == b'\\' {
+                i += 1;
+            } else if c == b'"' {
+                in_string = false;
+            }
+        } else if line_start && depth == 0 && content[i..].starts_with(keyword) {
+            let header_end = content[i..].find('{').map(|p| i + p);
+            let header = header_end.map(|end| &content[i + keyword.len()..end]);
+            if let (Some(end), Some(header)) = (header_end, header)
+                && header.starts_with([' ', '\t', '"', '{'])
+                && !header.contains(['=', '\n'])
+                && let Some(close) = closing_brace(&content[end + 1..])
+            {
+                let labels = header.split('"').skip(1).step_by(2).map(String::from).collect();
+                found.push((labels, &content[end + 1..end + 1 + close]));
+                i = end + 1 + close + 1;
+                continue;
+            }
+        } else {
+            match c {
+                b'"' => in_string = true,
+                b'{' => depth += 1,
+                b'}' => depth -= 1,
+                _ => {}
+            }
+        }
+        line_start = c == b'\n' || (line_start && c.is_ascii_whitespace());
+        i += 1;
+    }
+    found
+}
+
+/// Index of the brace closing a block that's already been opened
+fn closing_brace(body: &str) -> Option<usize> {
+    let mut depth = 1;
+    let mut in_string = false;
+    let mut escaped = false;
+    for (i, c) in body.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if in_string => escaped = true,
+            '"' => in_string = !in_string,
+            '{' if !in_string => depth += 1,
+            '}' if !in_string => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// A top-level `name = "value"` attribute of a block body
+fn attribute(body: &str, name: &str) -> Option<String> {
+    let mut depth = 0;
+    for line in body.lines() {
+        let trimmed = line.trim();
+        if depth == 0
+            && let Some(rest) = trimmed.strip_prefix(name)
+            && let Some(value) = rest.trim_start().strip_prefix('=')
+        {
+            let value = value.trim().trim_end_matches([',', '}']).trim();
+            return value.strip_prefix('"')?.split('"').next().map(String::from);
+        }
+        depth = (depth + line.matches('{').count()).saturating_sub(line.matches('}').count());
+    }
+    None
+}
+
+/// Drop `#`, `//` and `/* */` comments outside string literals
+fn strip_comments(content: &str) -> String {
+    let mut out = String::with_capacity(content.len());
+    let mut chars = content.chars().peekable();
+    let mut in_string = false;
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => {
+                in_string = !in_string;
+                out.push(c);
+            }
+            '\\' if in_string => {
+                out.push(c);
+                out.extend(chars.next());
+            }
+            '#' if !in_string => skip_line(&mut chars, &mut out),
+            '/' if !in_string && chars.peek() == Some(&'/') => skip_line(&mut chars, &mut out),
+            '/' if !in_string && chars.peek() == Some(&'*') => {
+                chars.next();
+                let mut previous = ' ';
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        out.push('\n');
+                    }
+                    if previous == '*' && c == '/' {
+                        break;
+                    }
+                    previous = c;
+                }
+            }
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+fn skip_line(chars: &mut std::iter::Peekable<std::str::Chars>, out: &mut String) {
+    for c in chars.by_ref() {
+        if c == '\n' {
+            out.push('\n');
+            break;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAIN_TF: &str = r#"
+terraform {
+  required_version = ">= 1.5"
+
+  required_providers {
+    aws = {
+      source  = "hashicorp/aws"
+      version = "~> 5.0"
+    }
+    random = {
+      source = "registry.terraform.io/hashicorp/random"
+      version = ">= 3.5, < 4.0"
+    }
+    # legacy shorthand
+    null = "~> 3.2"
+    cloudflare = {
+      source = "cloudflare/cloudflare"
+      configuration_aliases = [cloudflare.dns]
+    }
+  }
+}
+
+provider "aws" {
+  region = "eu-west-1" # not a requirement
+}
+
+module "vpc" {
+  source  = "terraform-aws-modules/vpc/aws"
+  version = "5.1.2"
+
+  name = "main"
+  tags = { Team = "infra" }
+}
+
+module "eks" {
+  source  = "terraform-aws-modules/eks/aws//modules/karpenter"
+  version = "~> 20.0"
+}
+
+/* module "old" {
+  source  = "terraform-aws-modules/old/aws"
+  version = "1.0.0"
+} */
+
+module "local" {
+  source = "./modules/network"
+}
+
+module "git" {
+  source = "git::https://github.com/acme/module.git?ref=v1.2.0"
+}
+
+module "private" {
+  source  = "app.terraform.io/acme/network/aws"
+  version = "2.0.0"
+}
+"#;
+
+    const LOCK: &str = r#"# This file is maintained automatically by "terraform init".
+provider "registry.terraform.io/hashicorp/aws" {
+  version     = "5.31.0"
+  constraints = "~> 5.0"
+  hashes = [
+    "h1:abc=",
+  ]
+}
+
+provider "registry.terraform.io/hashicorp/tls" {
+  version = "4.0.5"
+}
+"#;
+
+    fn summary(deps: &[Dependency]) -> Vec<(&str, Option<&str>, Option<&str>, bool)> {
+        deps.iter()
+            .map(|d| (d.name.as_str(), d.version.as_deref(), d.constraint.as_deref(), d.transitive))
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_required_providers() {
+        let providers = parse_required_providers(&strip_comments(MAIN_TF));
+        let summary: Vec<_> =
+            providers.iter().map(|p| (p.address.as_str(), p.constraint.as_deref())).collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("hashicorp/aws", Some("~> 5.0")),
+                ("hashicorp/random", Some(">= 3.5, < 4.0")),
+                ("hashicorp/null", Some("~> 3.2")),
+                ("cloudflare/cloudflare", None),
+            ]
+        );
+        assert!(providers.iter().all(|p| p.host.is_none()));
+    }
+
+    #[test]
+    fn test_parse_modules() {
+        let modules = parse_modules(&strip_comments(MAIN_TF));
+        let summary: Vec<_> = modules
+            .iter()
+            .map(|(name, m)| (name.as_str(), m.address.as_str(), m.host.as_deref()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("vpc", "terraform-aws-modules/vpc/aws", None),
+                ("eks", "terraform-aws-modules/eks/aws", None),
+                ("private", "acme/network/aws", Some("app.terraform.io")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_resolve() {
+        let content = strip_comments(MAIN_TF);
+        let installed = vec![("eks".to_string(), "20.8.4".to_string())];
+        let deps = resolve(
+            &parse_required_providers(&content),
+            &parse_modules(&content),
+            &parse_lock(LOCK),
+            &installed,
+        );
+        assert_eq!(
+            summary(&deps),
+            vec![
+                ("hashicorp/aws", Some("5.31.0"), Some("~> 5.0"), false),
+                ("hashicorp/random", None, Some(">= 3.5, < 4.0"), false),
+                ("hashicorp/null", None, Some("~> 3.2"), false),
+                ("cloudflare/cloudflare", None, None, false),
+                ("terraform-aws-modules/vpc/aws", Some("5.1.2"), Some("5.1.2"), false),
+                ("terraform-aws-modules/eks/aws", Some("20.8.4"), Some("~> 20.0"), false),
+                ("acme/network/aws", Some("2.0.0"), Some("2.0.0"), false),
+                ("hashicorp/tls", Some("4.0.5"), None, true),
+            ]
+        );
+        assert_eq!(deps[6].registry.as_deref(), Some("app.terraform.io"));
+    }
+
+    #[test]
+    fn test_exact_version() {
+        assert_eq!(exact_version("5.1.2"), Some("5.1.2".to_string()));
+        assert_eq!(exact_version("= 5.1.2"), Some("5.1.2".to_string()));
+        assert_eq!(exact_version("~> 5.0"), None);
+        assert_eq!(exact_version(">= 3.5, < 4.0"), None);
+        assert_eq!(exact_version("!= 5.1.2"), None);
+    }
+
+    #[test]
+    fn test_opentofu_lock_sets_registry() {
+        let lock = r#"provider "registry.opentofu.org/hashicorp/aws" {
+  version = "5.40.0"
+}"#;
+        let providers = parse_required_providers(&strip_comments(MAIN_TF));
+        let deps = resolve(&providers[..1], &[], &parse_lock(lock), &[]);
+        assert_eq!(deps[0].version.as_deref(), Some("5.40.0"));
+        assert_eq!(deps[0].registry.as_deref(), Some("registry.opentofu.org"));
+    }
+
+    #[test]
+    fn test_installed_modules() {
+        let manifest: Value = serde_json::from_str(
+            r#"{"Modules":[{"Key":"","Source":"","Dir":"."},
+                {"Key":"vpc","Source":"registry.terraform.io/terraform-aws-modules/vpc/aws","Version":"5.1.2","Dir":".terraform/modules/vpc"},
+                {"Key":"vpc.nested","Source":"registry.terraform.io/x/y/aws","Version":"1.0.0","Dir":"x"}]}"#,
+        )
+        .unwrap_or_default();
+        assert_eq!(installed_modules(&manifest), vec![("vpc".to_string(), "5.1.2".to_string())]);
+    }
+}
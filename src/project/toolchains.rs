@@ -0,0 +1,195 @@
+//! Runtime pin files: asdf `.tool-versions`, `mise.toml`, `.nvmrc`, `.node-version`,
+//! `.python-version`, `.ruby-version` and `rust-toolchain(.toml)`
+
+use super::{Dependency, ProjectInfo};
+use std::fs;
+
+/// Single-runtime pin files and the runtime they pin
+const PIN_FILES: &[(&str, &str)] = &[
+    (".nvmrc", "node"),
+    (".node-version", "node"),
+    (".python-version", "python"),
+    (".ruby-version", "ruby"),
+    ("rust-toolchain", "rust"),
+];
+
+pub fn scan() -> Option<ProjectInfo> {
+    let mut packages = Vec::new();
+    if let Ok(content) = fs::read_to_string(".tool-versions") {
+        packages.extend(parse_tool_versions(&content));
+    }
+    for file in ["mise.toml", ".mise.toml"] {
+        if let Ok(content) = fs::read_to_string(file) {
+            packages.extend(parse_mise(&content, file));
+        }
+    }
+    for (file, tool) in PIN_FILES {
+        if let Ok(content) = fs::read_to_string(file) {
+            let pin = content.lines().map(str::trim).find(|l| !l.is_empty() && !l.starts_with('#'));
+            packages.extend(pin.and_then(|pin| runtime(tool, pin, file)));
+        }
+    }
+    if let Ok(content) = fs::read_to_string("rust-toolchain.toml") {
+        packages.extend(parse_rust_toolchain(&content));
+    }
+    if packages.is_empty() {
+        return None;
+    }
+
+    Some(ProjectInfo { file: "toolchain pins", source: "path", packages })
+}
+
+/// `nodejs 20.11.0 18.19.0` lines; the first version listed is the one in use
+fn parse_tool_versions(content: &str) -> Vec<Dependency> {
+    content
+        .lines()
+        .enumerate()
+        .filter_map(|(line, text)| {
+            let text = text.split('#').next()?;
+            let mut fields = text.split_whitespace();
+            let (tool, version) = (fields.next()?, fields.next()?);
+            runtime(tool, version, &format!(".tool-versions:{}", line + 1))
+        })
+        .collect()
+}
+
+/// The `[tools]` table: `node = "20"`, `python = ["3.12", "3.11"]` or
+/// `rust = { version = "1.75" }`
+fn parse_mise(content: &str, file: &str) -> Vec<Dependency> {
+    let Ok(doc) = toml::from_str::<toml::Value>(content) else { return Vec::new() };
+    let Some(tools) = doc.get("tools").and_then(toml::Value::as_table) else { return Vec::new() };
+    tools
+        .iter()
+        .filter_map(|(tool, value)| {
+            let value = match value {
+                toml::Value::Array(versions) => versions.first()?,
+                toml::Value::Table(options) => options.get("version")?,
+                value => value,
+            };
+            let tool = tool.strip_prefix("core:").unwrap_or(tool);
+            runtime(tool, value.as_str()?, file)
+        })
+        .collect()
+}
+
+/// `[toolchain] channel = "1.75.0"`; named channels like `stable` track the
+/// latest release by definition
+fn parse_rust_toolchain(content: &str) -> Option<Dependency> {
+    let doc: toml::Value = toml::from_str(content).ok()?;
+    let channel = doc.get("toolchain")?.get("channel")?.as_str()?;
+    runtime("rust", channel, "rust-toolchain.toml")
+}
+
+/// A pin on one of the runtimes with an official release source. Version prefixes
+/// (`v20`, `ruby-3.3.0`, `temurin-21`) are dropped; aliases other than Node's LTS lines, and
+/// distributions like `pypy3.10`, are skipped
+fn runtime(tool: &str, pin: &str, location: &str) -> Option<Dependency> {
+    let (name, source) = match tool {
+        "node" | "nodejs" => ("node", "node"),
+        "python" => ("python", "python"),
+        "ruby" => ("ruby", "ruby"),
+        "rust" => ("rust", "rust"),
+        "go" | "golang" => ("go", "golang"),
+        "java" => ("java", "java"),
+        _ => return None,
+    };
+    let pin = pin.trim().trim_matches(['"', '\'']);
+
+    // `lts/*` and `lts/iron` float with an LTS line, so only the latest is shown
+    if name == "node" && (pin == "lts" || pin.starts_with("lts/")) {
+        let channel = if pin == "lts/*" { "lts" } else { pin };
+        return Some(Dependency {
+            source: Some(source),
+            location: Some(location.to_string()),
+            ..Dependency::new(format!("node@{}", channel.to_ascii_lowercase()))
+        });
+    }
+
+    let version = ["v", "ruby-", "python-", "go", "temurin-", "openjdk-"]
+        .iter()
+        .find_map(|prefix| pin.strip_prefix(prefix))
+        .unwrap_or(pin);
+    // Java build numbers (`21.0.5+11`) aren't part of the release version
+    let version = version.split('+').next().unwrap_or(version);
+    if !version.starts_with(|c: char| c.is_ascii_digit())
+        || !version.chars().all(|c| c.is_ascii_digit() || c == '.')
+    {
+        return None;
+    }
+    Some(Dependency {
+        version: Some(version.to_string()),
+        source: Some(source),
+        location: Some(location.to_string()),
+        ..Dependency::new(name)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Summary<'a> = (&'a str, Option<&'a str>, Option<&'a str>, Option<&'a str>);
+
+    fn summary(deps: &[Dependency]) -> Vec<Summary<'_>> {
+        deps.iter()
+            .map(|d| (d.name.as_str(), d.version.as_deref(), d.source, d.location.as_deref()))
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_tool_versions() {
+        let content = "# runtimes\nnodejs 20.11.0 18.19.0\npython 3.12.1\n\
+                       golang 1.22.0  # toolchain\nterraform 1.6.0\nruby system\n";
+        assert_eq!(
+            summary(&parse_tool_versions(content)),
+            vec![
+                ("node", Some("20.11.0"), Some("node"), Some(".tool-versions:2")),
+                ("python", Some("3.12.1"), Some("python"), Some(".tool-versions:3")),
+                ("go", Some("1.22.0"), Some("golang"), Some(".tool-versions:4")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_mise() {
+        let content = r#"[env]
+NODE_ENV = "production"
+
+[tools]
+"core:node" = "lts"
+python = ["3.12", "3.11"]
+rust = { version = "1.75", profile = "minimal" }
+ruby = "latest"
+"#;
+        assert_eq!(
+            summary(&parse_mise(content, "mise.toml")),
+            vec![
+                ("node@lts", None, Some("node"), Some("mise.toml")),
+                ("python", Some("3.12"), Some("python"), Some("mise.toml")),
+                ("rust", Some("1.75"), Some("rust"), Some("mise.toml")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_runtime_pins() {
+        let pin = |tool, pin| runtime(tool, pin, "file").map(|d| (d.name, d.version));
+        assert_eq!(pin("node", "v20.11.0"), Some(("node".into(), Some("20.11.0".into()))));
+        assert_eq!(pin("node", "lts/*"), Some(("node@lts".into(), None)));
+        assert_eq!(pin("node", "lts/Iron"), Some(("node@lts/iron".into(), None)));
+        assert_eq!(pin("node", "node"), None);
+        assert_eq!(pin("ruby", "ruby-3.3.0"), Some(("ruby".into(), Some("3.3.0".into()))));
+        assert_eq!(pin("python", "pypy3.10-7.3.12"), None);
+        assert_eq!(pin("python", "3.12.0rc1"), None);
+        assert_eq!(pin("java", "temurin-21.0.5+11"), Some(("java".into(), Some("21.0.5".into()))));
+        assert_eq!(pin("java", "zulu-21.38.21"), None);
+    }
+
+    #[test]
+    fn test_parse_rust_toolchain() {
+        let pinned = "[toolchain]\nchannel = \"1.75.0\"\ncomponents = [\"clippy\"]\n";
+        let dep = parse_rust_toolchain(pinned);
+        assert_eq!(dep.and_then(|d| d.version).as_deref(), Some("1.75.0"));
+        assert_eq!(parse_rust_toolchain("[toolchain]\nchannel = \"stable\"\n"), None);
+    }
+}
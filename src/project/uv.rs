@@ -0,0 +1,152 @@
+//! uv.lock scanning
+
+use super::{Dependency, ProjectInfo};
+use crate::sources::normalize_name;
+use std::collections::HashSet;
+use std::fs;
+
+/// The public index; packages locked from it use the regular pypi.org JSON API
+const PYPI_SIMPLE: &str = "https://pypi.org/simple";
+
+pub fn scan() -> Option<ProjectInfo> {
+    let content = fs::read_to_string("uv.lock").ok()?;
+    let packages = parse_uv_lock(&content)?;
+
+    if packages.is_empty() {
+        return None;
+    }
+
+    Some(ProjectInfo { file: "uv.lock", source: "pypi", packages })
+}
+
+/// Workspace members are locked from the project tree itself
+fn is_member(package: &toml::Value) -> bool {
+    package.get("source").is_some_and(|s| s.get("editable").is_some() || s.get("virtual").is_some())
+}
+
+fn parse_uv_lock(content: &str) -> Option<Vec<Dependency>> {
+    let doc: toml::Value = toml::from_str(content).ok()?;
+    let locked = doc.get("package")?.as_array()?;
+
+    // Anything a workspace member depends on (including extras and dev groups) is direct
+    let mut direct = HashSet::new();
+    for member in locked.iter().filter(|p| is_member(p)) {
+        let groups = ["optional-dependencies", "dev-dependencies"]
+            .iter()
+            .filter_map(|key| member.get(key)?.as_table())
+            .flat_map(|table| table.values());
+        let lists = member.get("dependencies").into_iter().chain(groups);
+        for dep in lists.filter_map(toml::Value::as_array).flatten() {
+            if let Some(name) = dep.get("name").and_then(toml::Value::as_str) {
+                direct.insert(normalize_name(name));
+            }
+        }
+    }
+
+    let mut packages: Vec<Dependency> = locked
+        .iter()
+        .filter(|p| !is_member(p))
+        .filter_map(|p| {
+            let name = p.get("name")?.as_str()?;
+            let version = p.get("version")?.as_str()?;
+            // git, path and direct URL sources have no registry to compare against
+            let registry = p.get("source")?.get("registry")?.as_str()?;
+            Some(Dependency {
+                version: Some(version.to_string()),
+                registry: (registry.trim_end_matches('/') != PYPI_SIMPLE)
+                    .then(|| registry.to_string()),
+                transitive: !direct.contains(&normalize_name(name)),
+                ..Dependency::new(name)
+            })
+        })
+        .collect();
+
+    packages.sort_by_key(|d| d.transitive); // Direct dependencies first
+    Some(packages)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LOCK: &str = r#"
+version = 1
+requires-python = ">=3.12"
+
+[[package]]
+name = "myapp"
+version = "0.1.0"
+source = { editable = "." }
+dependencies = [
+    { name = "requests" },
+]
+
+[package.optional-dependencies]
+s3 = [{ name = "boto3" }]
+
+[package.dev-dependencies]
+dev = [{ name = "pytest" }]
+
+[[package]]
+name = "urllib3"
+version = "2.2.1"
+source = { registry = "https://pypi.org/simple" }
+
+[[package]]
+name = "requests"
+version = "2.31.0"
+source = { registry = "https://pypi.org/simple" }
+dependencies = [{ name = "urllib3" }]
+
+[[package]]
+name = "boto3"
+version = "1.34.0"
+source = { registry = "https://pypi.org/simple" }
+
+[[package]]
+name = "pytest"
+version = "8.0.0"
+source = { registry = "https://pypi.internal.example/simple" }
+
+[[package]]
+name = "vendored"
+version = "0.3.0"
+source = { git = "https://github.com/org/vendored?rev=main#abc123" }
+"#;
+
+    #[test]
+    fn test_parse_uv_lock_direct_and_transitive() {
+        let packages = parse_uv_lock(LOCK).unwrap_or_default();
+        let names: Vec<_> = packages.iter().map(|d| (d.name.as_str(), d.transitive)).collect();
+        assert_eq!(
+            names,
+            vec![("requests", false), ("boto3", false), ("pytest", false), ("urllib3", true)]
+        );
+    }
+
+    #[test]
+    fn test_parse_uv_lock_versions_and_registry() {
+        let packages = parse_uv_lock(LOCK).unwrap_or_default();
+        let requests = packages.iter().find(|d| d.name == "requests");
+        assert_eq!(requests.and_then(|d| d.version.as_deref()), Some("2.31.0"));
+        assert_eq!(requests.and_then(|d| d.registry.as_deref()), None);
+
+        let pytest = packages.iter().find(|d| d.name == "pytest");
+        assert_eq!(
+            pytest.and_then(|d| d.registry.as_deref()),
+            Some("https://pypi.internal.example/simple")
+        );
+    }
+
+    #[test]
+    fn test_parse_uv_lock_skips_project_and_git() {
+        let packages = parse_uv_lock(LOCK).unwrap_or_default();
+        assert!(!packages.iter().any(|d| d.name == "myapp" || d.name == "vendored"));
+    }
+
+    #[test]
+    fn test_parse_uv_lock_invalid() {
+        assert_eq!(parse_uv_lock("not toml ["), None);
+        assert_eq!(parse_uv_lock("version = 1"), None);
+    }
+}
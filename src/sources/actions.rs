@@ -0,0 +1,50 @@
+use super::{Ecosystem, Source, github, is_newer_within};
+
+/// GitHub Actions, versioned by the tags of the action's repository. Actions in a
+/// subdirectory (`github/codeql-action/init`) share their repository's tags
+pub struct ActionsSource;
+
+impl Source for ActionsSource {
+    fn name(&self) -> &'static str {
+        "actions"
+    }
+
+    fn ecosystem(&self) -> Ecosystem {
+        Ecosystem::Actions
+    }
+
+    fn get_version(&self, package: &str) -> Option<String> {
+        let mut parts = package.split('/');
+        let (owner, repo) = (parts.next()?, parts.next()?);
+        github::latest_tag(owner, repo)
+    }
+
+    /// A floating major tag (`v4`) follows every release in that major, so only
+    /// the components it names are compared
+    fn is_newer(&self, installed: &str, latest: &str) -> bool {
+        is_newer_within(installed, latest)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_floating_tags() {
+        let actions = ActionsSource;
+        assert!(!actions.is_newer("4", "4.2.2"));
+        assert!(actions.is_newer("3", "4.2.2"));
+        assert!(!actions.is_newer("4.2", "4.2.2"));
+        assert!(actions.is_newer("4.1.1", "4.2.2"));
+        assert!(!actions.is_newer("4.2.2", "4.2.2"));
+    }
+
+    #[test]
+    fn test_actions_source_properties() {
+        let actions = ActionsSource;
+        assert_eq!(actions.name(), "actions");
+        assert_eq!(actions.ecosystem(), Ecosystem::Actions);
+        assert!(!actions.is_local());
+    }
+}
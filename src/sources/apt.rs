@@ -30,13 +30,13 @@ mod tests {
 
     #[test]
     fn test_parse_apt_output() {
-        let output = r#"Package: curl
+        let output = r"Package: curl
 Version: 7.88.1-10+deb12u5
 Priority: optional
 Section: web
 Maintainer: Alessandro Ghedini <ghedo@debian.org>
 Installed-Size: 518
-"#;
+";
         assert_eq!(extract_version_field(output), Some("7.88.1-10+deb12u5".to_string()));
     }
 
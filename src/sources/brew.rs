@@ -58,21 +58,20 @@ mod tests {
 
         if let Some(formula) = parsed.get("formulae").and_then(|f| f.get(0)) {
             let name = formula.get("name").and_then(|n| n.as_str());
-            if name == Some(package) {
-                if let Some(version) =
+            if name == Some(package)
+                && let Some(version) =
                     formula.get("versions").and_then(|v| v.get("stable")).and_then(|s| s.as_str())
-                {
-                    return Some(version.to_string());
-                }
+            {
+                return Some(version.to_string());
             }
         }
 
         if let Some(cask) = parsed.get("casks").and_then(|c| c.get(0)) {
             let token = cask.get("token").and_then(|t| t.as_str());
-            if token == Some(package) {
-                if let Some(version) = cask.get("version").and_then(|v| v.as_str()) {
-                    return Some(version.to_string());
-                }
+            if token == Some(package)
+                && let Some(version) = cask.get("version").and_then(|v| v.as_str())
+            {
+                return Some(version.to_string());
             }
         }
 
@@ -13,15 +13,89 @@ impl Source for ComposerSource {
     }
 
     fn get_version(&self, package: &str) -> Option<String> {
-        let url = format!("https://repo.packagist.org/p2/{}.json", urlencoding::encode(package));
-        let output = Command::new("curl").args(["-sf", "-m", "10", &url]).output().ok()?;
-        if !output.status.success() {
-            return None;
+        let url = package_url("https://repo.packagist.org/p2/%package%.json", package);
+        parse_composer_response(&fetch(&url)?, package)
+    }
+
+    /// `registry` lists one or more repositories, separated by spaces, in the order
+    /// Composer searches them. As in Composer, the first repository that serves the
+    /// package is the only one asked, so a private package never reaches packagist.org
+    fn get_version_from(&self, package: &str, registry: &str) -> Option<String> {
+        for base in registry.split_whitespace().map(|r| r.trim_end_matches('/')) {
+            // A repository that can't be reached might serve the package; stop there
+            // rather than ask the next one
+            let index = fetch(&format!("{base}/packages.json"))?;
+            if !serves(&index, package) {
+                continue;
+            }
+            // Composer v2 repositories advertise where per-package metadata lives
+            let template =
+                parse_metadata_url(&index).unwrap_or_else(|| "/p2/%package%.json".to_string());
+            let template =
+                if template.starts_with('/') { format!("{base}{template}") } else { template };
+            if let Some(json) = fetch(&package_url(&template, package)) {
+                return parse_composer_response(&json, package);
+            }
+        }
+        None
+    }
+}
+
+fn fetch(url: &str) -> Option<String> {
+    let output = Command::new("curl").args(["-sf", "-m", "10", url]).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    Some(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Fill in `%package%`, keeping the `vendor/name` slash unescaped
+fn package_url(template: &str, package: &str) -> String {
+    let escaped: Vec<_> = package.split('/').map(urlencoding::encode).collect();
+    template.replace("%package%", &escaped.join("/"))
+}
+
+fn parse_metadata_url(json: &str) -> Option<String> {
+    let parsed: serde_json::Value = serde_json::from_str(json).ok()?;
+    parsed.get("metadata-url")?.as_str().map(String::from)
+}
+
+/// Whether a repository's `packages.json` admits to serving `package`: its
+/// `available-packages` list or `available-package-patterns` globs, when given
+fn serves(json: &str, package: &str) -> bool {
+    let Ok(parsed) = serde_json::from_str::<serde_json::Value>(json) else { return false };
+    let strings = |key| {
+        parsed.get(key).and_then(serde_json::Value::as_array).map(|list| {
+            list.iter().filter_map(serde_json::Value::as_str).map(String::from).collect::<Vec<_>>()
+        })
+    };
+    match (strings("available-packages"), strings("available-package-patterns")) {
+        (None, None) => true,
+        (names, patterns) => {
+            names.unwrap_or_default().iter().any(|n| n == package)
+                || patterns.unwrap_or_default().iter().any(|p| glob_match(p, package))
         }
-        parse_composer_response(&String::from_utf8_lossy(&output.stdout), package)
     }
 }
 
+/// `*` matches any run of characters, as in `acme/*`
+fn glob_match(pattern: &str, name: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let first = parts.next().unwrap_or_default();
+    let Some(mut rest) = name.strip_prefix(first) else { return false };
+    let parts: Vec<&str> = parts.collect();
+    for (i, part) in parts.iter().enumerate() {
+        if i == parts.len() - 1 {
+            return rest.ends_with(part);
+        }
+        match rest.find(part) {
+            Some(at) => rest = &rest[at + part.len()..],
+            None => return false,
+        }
+    }
+    rest.is_empty()
+}
+
 fn parse_composer_response(json: &str, package: &str) -> Option<String> {
     let parsed: serde_json::Value = serde_json::from_str(json).ok()?;
     let packages = parsed.get("packages")?;
@@ -54,6 +128,44 @@ mod tests {
         assert_eq!(parse_composer_response(json, "not/found"), None);
     }
 
+    #[test]
+    fn test_package_url() {
+        assert_eq!(
+            package_url("https://repo.packagist.org/p2/%package%.json", "monolog/monolog"),
+            "https://repo.packagist.org/p2/monolog/monolog.json"
+        );
+    }
+
+    #[test]
+    fn test_parse_metadata_url() {
+        let json = r#"{"packages":[],"metadata-url":"/p2/%package%.json","available-packages":[]}"#;
+        assert_eq!(parse_metadata_url(json), Some("/p2/%package%.json".to_string()));
+        assert_eq!(parse_metadata_url(r#"{"packages":{}}"#), None);
+    }
+
+    #[test]
+    fn test_serves() {
+        let listed = r#"{"metadata-url":"/p2/%package%.json","available-packages":["acme/lib"]}"#;
+        assert!(serves(listed, "acme/lib"));
+        assert!(!serves(listed, "monolog/monolog"));
+        let patterns = r#"{"available-package-patterns":["acme/*","*-bundle"]}"#;
+        assert!(serves(patterns, "acme/private-lib"));
+        assert!(serves(patterns, "other/foo-bundle"));
+        assert!(!serves(patterns, "monolog/monolog"));
+        assert!(serves(r#"{"metadata-url":"/p2/%package%.json"}"#, "anything/at-all"));
+        assert!(!serves("not json", "acme/lib"));
+    }
+
+    #[test]
+    fn test_glob_match() {
+        assert!(glob_match("acme/*", "acme/lib"));
+        assert!(glob_match("*/lib", "acme/lib"));
+        assert!(glob_match("acme/*-lib", "acme/private-lib"));
+        assert!(!glob_match("acme/*-lib", "acme/private"));
+        assert!(glob_match("acme/lib", "acme/lib"));
+        assert!(!glob_match("acme/lib", "acme/lib2"));
+    }
+
     #[test]
     fn test_composer_source_properties() {
         let composer = ComposerSource;
@@ -57,7 +57,7 @@ mod tests {
 
     #[test]
     fn test_parse_conda_output_not_found() {
-        let json = r#"{}"#;
+        let json = r"{}";
         assert_eq!(parse_conda_output(json, "nonexistent"), None);
     }
 
@@ -0,0 +1,64 @@
+use super::{Ecosystem, Source, matches_requirement};
+use std::process::Command;
+
+/// The Dart SDK itself, from the stable release channel
+pub struct DartSource;
+
+impl Source for DartSource {
+    fn name(&self) -> &'static str {
+        "dart"
+    }
+
+    fn ecosystem(&self) -> Ecosystem {
+        Ecosystem::System
+    }
+
+    fn get_version(&self, package: &str) -> Option<String> {
+        if package != "dart" {
+            return None;
+        }
+        let output = Command::new("curl")
+            .args([
+                "-sf",
+                "-m",
+                "10",
+                "https://storage.googleapis.com/dart-archive/channels/stable/release/latest/VERSION",
+            ])
+            .output()
+            .ok()?;
+        if !output.status.success() {
+            return None;
+        }
+        parse_dart_version(&String::from_utf8_lossy(&output.stdout))
+    }
+
+    fn satisfies(&self, version: &str, requirement: &str) -> Option<bool> {
+        matches_requirement(version, requirement)
+    }
+}
+
+fn parse_dart_version(json: &str) -> Option<String> {
+    let parsed: serde_json::Value = serde_json::from_str(json).ok()?;
+    parsed.get("version")?.as_str().map(String::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_dart_version() {
+        let json = r#"{"date":"2024-10-17","version":"3.5.4","revision":"d9f1ad5"}"#;
+        assert_eq!(parse_dart_version(json), Some("3.5.4".to_string()));
+        assert_eq!(parse_dart_version("not json"), None);
+    }
+
+    #[test]
+    fn test_dart_source_properties() {
+        let dart = DartSource;
+        assert_eq!(dart.name(), "dart");
+        assert_eq!(dart.ecosystem(), Ecosystem::System);
+        assert!(!dart.is_local());
+        assert_eq!(dart.get_version("flutter"), None);
+    }
+}
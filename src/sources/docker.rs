@@ -1,4 +1,4 @@
-use super::{Ecosystem, Source};
+use super::{Ecosystem, Source, oci};
 use std::process::Command;
 
 pub struct DockerSource;
@@ -13,95 +13,314 @@ impl Source for DockerSource {
     }
 
     fn get_version(&self, package: &str) -> Option<String> {
-        // Handle official images (no slash) vs user images (user/repo)
-        let repo_path =
-            if package.contains('/') { package.to_string() } else { format!("library/{package}") };
+        // `node@*-alpine` restricts the search to tags in the `-alpine` family
+        let (package, variant) = package.split_once("@*").unwrap_or((package, ""));
 
-        let url = format!(
-            "https://registry.hub.docker.com/v2/repositories/{}/tags?page_size=100",
-            urlencoding::encode(&repo_path).replace("%2F", "/") // Keep the slash
-        );
+        let tags = match registry_host(package) {
+            Some((host, repository)) => oci::list_tags(host, repository)?,
+            None => docker_hub_tags(package)?,
+        };
+        newest_tag(&tags, variant)
+    }
+
+    fn rebuilt(&self, package: &str, version: &str, digest: Option<&str>) -> bool {
+        let image = package.split_once("@*").map_or(package, |(image, _)| image);
+        let Some(local) = digest.map(String::from).or_else(|| local_digest(image, version)) else {
+            return false;
+        };
+        let (host, repository) = registry_host(image)
+            .map_or_else(|| (DOCKER_HUB_REGISTRY, hub_repository(image)), |(h, r)| (h, r.into()));
+        oci::manifest_digest(host, &repository, version).is_some_and(|remote| remote != local)
+    }
+
+    fn is_newer(&self, installed: &str, latest: &str) -> bool {
+        // Compare the version part only; `3.12-alpine3.19` vs `3.13-alpine3.20`
+        // shouldn't be decided by the variant's own numbers
+        match (split_tag(installed), split_tag(latest)) {
+            (Some((a, _)), Some((b, _))) => crate::is_newer(a, b),
+            _ => crate::is_newer(installed, latest),
+        }
+    }
+}
+
+const MAX_PAGES: usize = 10;
+const DOCKER_HUB_HOSTS: &[&str] = &["docker.io", "index.docker.io", "registry-1.docker.io"];
+/// Where Docker Hub serves the OCI distribution API
+const DOCKER_HUB_REGISTRY: &str = "registry-1.docker.io";
+
+/// Docker Hub's own tag API, which lists more than the OCI endpoint and can be
+/// ordered by update time
+fn docker_hub_tags(package: &str) -> Option<Vec<String>> {
+    let repo_path = hub_repository(package);
+
+    let mut url = Some(format!(
+        "https://registry.hub.docker.com/v2/repositories/{}/tags?page_size=100&ordering=last_updated",
+        urlencoding::encode(&repo_path).replace("%2F", "/") // Keep the slash
+    ));
+
+    // Recently updated tags come first, so a bounded number of pages covers
+    // every maintained release line
+    let mut tags = Vec::new();
+    for _ in 0..MAX_PAGES {
+        let Some(page_url) = url.take() else { break };
+        let output = Command::new("curl").args(["-sf", "-m", "10", &page_url]).output().ok()?;
+        if !output.status.success() {
+            break;
+        }
+        let (page, next) = parse_tag_page(&String::from_utf8_lossy(&output.stdout))?;
+        tags.extend(page);
+        url = next;
+    }
+    Some(tags)
+}
+
+/// Docker Hub repository path: official images (no slash) live under `library/`
+pub(super) fn hub_repository(image: &str) -> String {
+    let image = DOCKER_HUB_HOSTS
+        .iter()
+        .find_map(|host| image.strip_prefix(host)?.strip_prefix('/'))
+        .unwrap_or(image);
+    if image.contains('/') { image.to_string() } else { format!("library/{image}") }
+}
 
-        let output = Command::new("curl").args(["-sf", "-m", "10", &url]).output().ok()?;
+/// Digest of the locally pulled `image:tag`, from docker or podman
+fn local_digest(image: &str, tag: &str) -> Option<String> {
+    let reference = format!("{image}:{tag}");
+    ["docker", "podman"].iter().find_map(|cli| {
+        let output = Command::new(cli)
+            .args(["image", "inspect", "--format", "{{json .RepoDigests}}", &reference])
+            .output()
+            .ok()?;
         if !output.status.success() {
             return None;
         }
+        parse_repo_digests(&String::from_utf8_lossy(&output.stdout), image)
+    })
+}
 
-        parse_docker_tags(&String::from_utf8_lossy(&output.stdout))
+/// The digest from `RepoDigests` (`["python@sha256:..."]`), preferring the entry
+/// for `image` when the same content is tagged in several repositories
+fn parse_repo_digests(json: &str, image: &str) -> Option<String> {
+    let digests: Vec<String> = serde_json::from_str(json.trim()).ok()?;
+    let entries: Vec<(&str, &str)> = digests.iter().filter_map(|d| d.split_once('@')).collect();
+    entries
+        .iter()
+        .find(|(repo, _)| hub_repository(repo) == hub_repository(image))
+        .or_else(|| entries.first())
+        .map(|(_, digest)| (*digest).to_string())
+}
+
+/// The registry host and repository of an image on another registry
+/// (`ghcr.io/org/app`, `localhost:5000/app`); None for Docker Hub images
+fn registry_host(image: &str) -> Option<(&str, &str)> {
+    let (first, rest) = image.split_once('/')?;
+    let is_host = first.contains(['.', ':']) || first == "localhost";
+    (is_host && !DOCKER_HUB_HOSTS.contains(&first)).then_some((first, rest))
+}
+
+/// Split a tag into its version and variant suffix: `20.11-alpine` -> (`20.11`, `-alpine`).
+///
+/// Dates (`2024-01-15`) and calendar versions (`2024.01`) count as versions.
+/// None for tags that don't start with a version (`latest`, `bookworm`)
+#[must_use]
+pub fn split_tag(tag: &str) -> Option<(&str, &str)> {
+    let tag = tag.strip_prefix('v').unwrap_or(tag);
+    let end = tag.find(|c: char| !c.is_ascii_digit() && c != '.').unwrap_or(tag.len());
+    let mut version = tag[..end].trim_end_matches('.');
+    if version.len() == 4 && is_date(&tag[..tag.len().min(10)]) {
+        version = &tag[..10];
     }
+    version.starts_with(|c: char| c.is_ascii_digit()).then(|| (version, &tag[version.len()..]))
 }
 
-fn parse_docker_tags(json: &str) -> Option<String> {
+/// `YYYY-MM-DD`
+fn is_date(s: &str) -> bool {
+    s.len() == 10
+        && s.char_indices()
+            .all(|(i, c)| if i == 4 || i == 7 { c == '-' } else { c.is_ascii_digit() })
+}
+
+/// Date-stamped builds (`20240115`, `2024-01-15`) sort above any release number,
+/// so they're only compared with each other
+fn is_date_version(version: &str) -> bool {
+    is_date(version) || (version.len() == 8 && version.starts_with("20") && !version.contains('.'))
+}
+
+/// A variant with its own version numbers dropped, so `-alpine3.20` and `-alpine`
+/// are one family and `-slim-bookworm` another
+fn variant_family(variant: &str) -> String {
+    variant
+        .split('-')
+        .map(|word| word.trim_end_matches(|c: char| c.is_ascii_digit() || c == '.'))
+        .filter(|word| !word.is_empty())
+        .flat_map(|word| ["-", word])
+        .collect()
+}
+
+/// Tag names on one page of results, and the URL of the next page
+fn parse_tag_page(json: &str) -> Option<(Vec<String>, Option<String>)> {
     let parsed: serde_json::Value = serde_json::from_str(json).ok()?;
-    let results = parsed.get("results")?.as_array()?;
+    let tags = parsed
+        .get("results")?
+        .as_array()?
+        .iter()
+        .filter_map(|r| Some(r.get("name")?.as_str()?.to_string()))
+        .collect();
+    let next = parsed.get("next").and_then(|n| n.as_str()).map(String::from);
+    Some((tags, next))
+}
 
-    // Extract tag names, filter to version-like tags, sort semver
-    let mut versions: Vec<(semver::Version, String)> = results
+/// The highest version-like tag in the same variant family. Among equal versions
+/// the exact variant wins, then the one with the newest variant numbers
+pub(super) fn newest_tag(tags: &[String], variant: &str) -> Option<String> {
+    let family = variant_family(variant);
+    let candidates: Vec<(&str, &str, &String)> = tags
         .iter()
-        .filter_map(|r| r.get("name")?.as_str())
         .filter_map(|tag| {
-            // Try to parse as semver (handles v prefix)
-            let clean = tag.strip_prefix('v').unwrap_or(tag);
-            // Only consider tags that look like versions (start with digit)
-            if !clean.chars().next()?.is_ascii_digit() {
-                return None;
-            }
-            // Try to parse, padding with .0 if needed
-            let padded = pad_version(clean);
-            semver::Version::parse(&padded).ok().map(|v| (v, tag.to_string()))
+            let (version, suffix) = split_tag(tag)?;
+            (variant_family(suffix) == family).then_some((version, suffix, tag))
         })
         .collect();
+    // Release numbers are preferred when an image publishes both kinds
+    let releases = candidates.iter().any(|(v, _, _)| !is_date_version(v));
 
-    versions.sort_by(|a, b| b.0.cmp(&a.0)); // Descending
-    versions.first().map(|(_, tag)| tag.clone())
+    candidates
+        .into_iter()
+        .filter(|(v, _, _)| !releases || !is_date_version(v))
+        .max_by(|(a, a_suffix, _), (b, b_suffix, _)| {
+            parts(a)
+                .cmp(&parts(b))
+                .then_with(|| (*a_suffix == variant).cmp(&(*b_suffix == variant)))
+                .then_with(|| parts(a_suffix).cmp(&parts(b_suffix)))
+        })
+        .map(|(_, _, tag)| tag.clone())
 }
 
-fn pad_version(v: &str) -> String {
-    // Split on first non-version char (like -alpine, -slim)
-    let base = v.split(|c: char| !c.is_ascii_digit() && c != '.').next().unwrap_or(v);
-    let parts: Vec<&str> = base.split('.').collect();
-    match parts.len() {
-        1 => format!("{}.0.0", parts[0]),
-        2 => format!("{}.{}.0", parts[0], parts[1]),
-        _ => base.to_string(),
-    }
+/// Every number in a version or variant, for ordering: `2024.01.15` -> [2024, 1, 15]
+fn parts(s: &str) -> Vec<u64> {
+    s.split(|c: char| !c.is_ascii_digit()).filter_map(|p| p.parse().ok()).collect()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_pad_version() {
-        assert_eq!(pad_version("3"), "3.0.0");
-        assert_eq!(pad_version("3.21"), "3.21.0");
-        assert_eq!(pad_version("3.21.0"), "3.21.0");
-        assert_eq!(pad_version("3.21-alpine"), "3.21.0");
+    fn parse_docker_tags(json: &str, variant: &str) -> Option<String> {
+        newest_tag(&parse_tag_page(json)?.0, variant)
     }
 
     #[test]
     fn test_parse_docker_tags() {
         let json =
             r#"{"results":[{"name":"latest"},{"name":"3.21"},{"name":"3.20"},{"name":"alpine"}]}"#;
-        assert_eq!(parse_docker_tags(json), Some("3.21".to_string()));
+        assert_eq!(parse_docker_tags(json, ""), Some("3.21".to_string()));
     }
 
     #[test]
     fn test_parse_docker_tags_with_v_prefix() {
         let json = r#"{"results":[{"name":"v1.0.0"},{"name":"v0.9.0"}]}"#;
-        assert_eq!(parse_docker_tags(json), Some("v1.0.0".to_string()));
+        assert_eq!(parse_docker_tags(json, ""), Some("v1.0.0".to_string()));
+    }
+
+    #[test]
+    fn test_parse_docker_tags_same_variant() {
+        let json = r#"{"results":[{"name":"22.1-alpine"},{"name":"22.1"},{"name":"22-slim"},
+            {"name":"20.11-alpine"},{"name":"22.1-alpine3.20"}]}"#;
+        assert_eq!(parse_docker_tags(json, "-alpine"), Some("22.1-alpine".to_string()));
+        assert_eq!(parse_docker_tags(json, ""), Some("22.1".to_string()));
+        assert_eq!(parse_docker_tags(json, "-bookworm"), None);
+    }
+
+    #[test]
+    fn test_variant_family() {
+        let json = r#"{"results":[{"name":"3.13-alpine3.20"},{"name":"3.13-alpine3.21"},
+            {"name":"3.12-alpine"},{"name":"3.13-slim-bookworm"},{"name":"3.13"}]}"#;
+        assert_eq!(parse_docker_tags(json, "-alpine3.20"), Some("3.13-alpine3.20".to_string()));
+        assert_eq!(parse_docker_tags(json, "-alpine"), Some("3.13-alpine3.21".to_string()));
+        assert_eq!(
+            parse_docker_tags(json, "-slim-bookworm"),
+            Some("3.13-slim-bookworm".to_string())
+        );
+        assert_eq!(variant_family("-debian-12-r0"), "-debian-r");
+    }
+
+    #[test]
+    fn test_calver_and_date_tags() {
+        let json =
+            r#"{"results":[{"name":"2024.01.15"},{"name":"2023.12.01"},{"name":"2024.1.2"}]}"#;
+        assert_eq!(parse_docker_tags(json, ""), Some("2024.01.15".to_string()));
+
+        let json = r#"{"results":[{"name":"2024-01-15"},{"name":"2023-12-31"},{"name":"latest"}]}"#;
+        assert_eq!(parse_docker_tags(json, ""), Some("2024-01-15".to_string()));
+        assert_eq!(split_tag("2024-01-15-alpine"), Some(("2024-01-15", "-alpine")));
+
+        // Nightly date tags don't outrank releases
+        let json = r#"{"results":[{"name":"20240115"},{"name":"1.4.2"},{"name":"1.4.1"}]}"#;
+        assert_eq!(parse_docker_tags(json, ""), Some("1.4.2".to_string()));
+        assert!(DockerSource.is_newer("2023-12-31", "2024-01-15"));
+    }
+
+    #[test]
+    fn test_parse_tag_page_next() {
+        let json = r#"{"next":"https://hub.docker.com/v2/repositories/library/node/tags?page=2",
+            "results":[{"name":"22"}]}"#;
+        let (tags, next) = parse_tag_page(json).unwrap_or_default();
+        assert_eq!(tags, vec!["22".to_string()]);
+        assert!(next.is_some_and(|n| n.ends_with("page=2")));
+        assert_eq!(parse_tag_page(r#"{"next":null,"results":[]}"#), Some((Vec::new(), None)));
+    }
+
+    #[test]
+    fn test_registry_host() {
+        assert_eq!(registry_host("ghcr.io/acme/app"), Some(("ghcr.io", "acme/app")));
+        assert_eq!(
+            registry_host("quay.io/prometheus/node-exporter"),
+            Some(("quay.io", "prometheus/node-exporter"))
+        );
+        assert_eq!(registry_host("localhost:5000/app"), Some(("localhost:5000", "app")));
+        assert_eq!(registry_host("docker.io/library/node"), None);
+        assert_eq!(registry_host("bitnami/redis"), None);
+        assert_eq!(registry_host("node"), None);
+    }
+
+    #[test]
+    fn test_parse_repo_digests() {
+        let json = r#"["acme/python@sha256:aaa","python@sha256:bbb"]"#;
+        assert_eq!(parse_repo_digests(json, "python"), Some("sha256:bbb".to_string()));
+        let podman = r#"["docker.io/library/python@sha256:ccc"]"#;
+        assert_eq!(parse_repo_digests(podman, "python"), Some("sha256:ccc".to_string()));
+        assert_eq!(parse_repo_digests("[]", "python"), None);
+        assert_eq!(hub_repository("docker.io/bitnami/redis"), "bitnami/redis");
+        assert_eq!(hub_repository("python"), "library/python");
+    }
+
+    #[test]
+    fn test_split_tag() {
+        assert_eq!(split_tag("20.11-alpine"), Some(("20.11", "-alpine")));
+        assert_eq!(split_tag("3.12-slim-bookworm"), Some(("3.12", "-slim-bookworm")));
+        assert_eq!(split_tag("15.4"), Some(("15.4", "")));
+        assert_eq!(split_tag("v1.2.3"), Some(("1.2.3", "")));
+        assert_eq!(split_tag("latest"), None);
+        assert_eq!(split_tag("bookworm"), None);
+    }
+
+    #[test]
+    fn test_is_newer_ignores_variant_numbers() {
+        let docker = DockerSource;
+        assert!(docker.is_newer("3.12-alpine3.20", "3.13-alpine3.19"));
+        assert!(!docker.is_newer("3.13-alpine3.19", "3.12-alpine3.20"));
     }
 
     #[test]
     fn test_parse_docker_tags_empty() {
         let json = r#"{"results":[]}"#;
-        assert_eq!(parse_docker_tags(json), None);
+        assert_eq!(parse_docker_tags(json, ""), None);
     }
 
     #[test]
     fn test_parse_docker_tags_no_versions() {
         let json = r#"{"results":[{"name":"latest"},{"name":"alpine"}]}"#;
-        assert_eq!(parse_docker_tags(json), None);
+        assert_eq!(parse_docker_tags(json, ""), None);
     }
 
     #[test]
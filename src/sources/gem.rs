@@ -0,0 +1,98 @@
+use super::{Ecosystem, Source, matches_requirement};
+use std::process::Command;
+
+pub struct GemSource;
+
+impl Source for GemSource {
+    fn name(&self) -> &'static str {
+        "gem"
+    }
+
+    fn ecosystem(&self) -> Ecosystem {
+        Ecosystem::Ruby
+    }
+
+    fn get_version(&self, package: &str) -> Option<String> {
+        parse_gem_response(&curl(&format!(
+            "https://rubygems.org/api/v1/gems/{}.json",
+            urlencoding::encode(package)
+        ))?)
+    }
+
+    fn get_versions(&self, package: &str) -> Option<Vec<String>> {
+        parse_gem_versions(&curl(&format!(
+            "https://rubygems.org/api/v1/versions/{}.json",
+            urlencoding::encode(package)
+        ))?)
+    }
+
+    /// Gemfile requirements: `~> 7.1.3` allows 7.1.x, `~> 7.1` allows 7.x, and a
+    /// bare version is an exact pin
+    fn satisfies(&self, version: &str, requirement: &str) -> Option<bool> {
+        matches_requirement(version, requirement)
+    }
+}
+
+fn curl(url: &str) -> Option<String> {
+    let output = Command::new("curl").args(["-sf", "-m", "10", url]).output().ok()?;
+    output.status.success().then(|| String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+fn parse_gem_response(json: &str) -> Option<String> {
+    let parsed: serde_json::Value = serde_json::from_str(json).ok()?;
+    parsed.get("version")?.as_str().map(String::from)
+}
+
+/// Release versions from `[{"number": "7.1.4", "prerelease": false}, ...]`, once
+/// per version across platforms
+fn parse_gem_versions(json: &str) -> Option<Vec<String>> {
+    let parsed: serde_json::Value = serde_json::from_str(json).ok()?;
+    let mut versions: Vec<String> = parsed
+        .as_array()?
+        .iter()
+        .filter(|v| v.get("prerelease").and_then(serde_json::Value::as_bool) != Some(true))
+        .filter_map(|v| v.get("number")?.as_str().map(String::from))
+        .collect();
+    versions.dedup();
+    Some(versions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_gem_response() {
+        let json = r#"{"name":"rails","version":"7.2.1","platform":"ruby"}"#;
+        assert_eq!(parse_gem_response(json), Some("7.2.1".to_string()));
+        assert_eq!(parse_gem_response("{}"), None);
+    }
+
+    #[test]
+    fn test_parse_gem_versions() {
+        let json = r#"[{"number":"8.0.0.rc1","prerelease":true},
+            {"number":"7.1.4","platform":"ruby","prerelease":false},
+            {"number":"7.1.4","platform":"java","prerelease":false},
+            {"number":"7.1.3","platform":"ruby","prerelease":false}]"#;
+        assert_eq!(parse_gem_versions(json), Some(vec!["7.1.4".to_string(), "7.1.3".to_string()]));
+    }
+
+    #[test]
+    fn test_gem_requirements() {
+        let gem = GemSource;
+        assert_eq!(gem.satisfies("7.1.4", "~> 7.1.3"), Some(true));
+        assert_eq!(gem.satisfies("7.2.0", "~> 7.1.3"), Some(false));
+        assert_eq!(gem.satisfies("7.2.0", "~> 7.1"), Some(true));
+        assert_eq!(gem.satisfies("8.0.0", "~> 7.1"), Some(false));
+        assert_eq!(gem.satisfies("1.5.6", ">= 1.1, < 2.0"), Some(true));
+        assert_eq!(gem.satisfies("1.2.4", "1.2.3"), Some(false));
+    }
+
+    #[test]
+    fn test_gem_source_properties() {
+        let gem = GemSource;
+        assert_eq!(gem.name(), "gem");
+        assert_eq!(gem.ecosystem(), Ecosystem::Ruby);
+        assert!(!gem.is_local());
+    }
+}
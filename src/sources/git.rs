@@ -0,0 +1,75 @@
+use super::{Ecosystem, Source, github};
+use crate::is_newer;
+use std::process::Command;
+
+/// Tags of any git repository, by URL: GitHub repositories through its API, other
+/// hosts with `git ls-remote`
+pub struct GitSource;
+
+impl Source for GitSource {
+    fn name(&self) -> &'static str {
+        "git"
+    }
+
+    fn ecosystem(&self) -> Ecosystem {
+        Ecosystem::Git
+    }
+
+    fn get_version(&self, package: &str) -> Option<String> {
+        if package.contains("github.com/")
+            && let Some((owner, repo)) = github::parse_repo(package)
+        {
+            return github::latest_tag(&owner, &repo);
+        }
+        // Bare names (`latest black`) aren't repositories
+        if !package.contains("://") && !package.contains('@') {
+            return None;
+        }
+        let output = Command::new("git")
+            // `--` so a URL like `--upload-pack=...` can't be read as an option
+            .args(["ls-remote", "--tags", "--refs", "--", package])
+            // Fail instead of prompting for credentials on private repositories
+            .env("GIT_TERMINAL_PROMPT", "0")
+            .output()
+            .ok()?;
+        if !output.status.success() {
+            return None;
+        }
+        parse_ls_remote(&String::from_utf8_lossy(&output.stdout))
+    }
+}
+
+/// Highest release from `<sha>\trefs/tags/<tag>` lines, without its `v` prefix
+fn parse_ls_remote(output: &str) -> Option<String> {
+    output
+        .lines()
+        .filter_map(|line| line.split_once("refs/tags/").map(|(_, tag)| tag.trim()))
+        .map(|tag| tag.strip_prefix('v').unwrap_or(tag))
+        .filter(|t| t.starts_with(|c: char| c.is_ascii_digit()) && !t.contains('-'))
+        .fold(None, |best: Option<&str>, t| match best {
+            Some(b) if !is_newer(b, t) => Some(b),
+            _ => Some(t),
+        })
+        .map(String::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_ls_remote() {
+        let output = "1a2b\trefs/tags/v0.9.0\n3c4d\trefs/tags/v0.10.1\n\
+            5e6f\trefs/tags/v1.0.0-rc1\n7a8b\trefs/tags/nightly\n";
+        assert_eq!(parse_ls_remote(output), Some("0.10.1".to_string()));
+        assert_eq!(parse_ls_remote(""), None);
+    }
+
+    #[test]
+    fn test_git_source_properties() {
+        let git = GitSource;
+        assert_eq!(git.name(), "git");
+        assert_eq!(git.ecosystem(), Ecosystem::Git);
+        assert!(!git.is_local());
+    }
+}
@@ -0,0 +1,243 @@
+//! GitHub repository tags, shared by sources versioned by git tags (Swift
+//! packages, GitHub Actions), and the releases source for tools published as
+//! GitHub releases
+
+use super::{Ecosystem, Source, highest_version, tag_version};
+use crate::is_newer;
+use serde_json::Value;
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+/// Tools published as GitHub releases: `owner/repo`, or `owner/repo@pre` to
+/// include prereleases. Compared against the `repo` command when it's on `$PATH`
+pub struct GithubSource;
+
+impl Source for GithubSource {
+    fn name(&self) -> &'static str {
+        "github"
+    }
+
+    fn ecosystem(&self) -> Ecosystem {
+        Ecosystem::System
+    }
+
+    fn get_version(&self, package: &str) -> Option<String> {
+        let (repo, prereleases) = split_prerelease(package);
+        let (owner, repo) = parse_repo(repo)?;
+        let url = format!("https://api.github.com/repos/{owner}/{repo}/releases?per_page=100");
+        parse_releases(&get(&url)?, prereleases)
+    }
+
+    fn command(&self, package: &str) -> Option<String> {
+        parse_repo(split_prerelease(package).0).map(|(_, repo)| repo)
+    }
+}
+
+/// `repo@pre` asks for prereleases as well as releases
+pub fn split_prerelease(package: &str) -> (&str, bool) {
+    package.strip_suffix("@pre").map_or((package, false), |repo| (repo, true))
+}
+
+/// The highest release tag of `owner/repo`, without its `v` prefix
+pub fn latest_tag(owner: &str, repo: &str) -> Option<String> {
+    let url = format!("https://api.github.com/repos/{owner}/{repo}/tags?per_page=100");
+    parse_tags(&get(&url)?)
+}
+
+/// Release tags of `owner/repo` (the first 100 by ref order), without their `v` prefix
+pub fn release_tags(owner: &str, repo: &str) -> Option<Vec<String>> {
+    let url = format!("https://api.github.com/repos/{owner}/{repo}/tags?per_page=100");
+    parse_release_tags(&get(&url)?)
+}
+
+/// GET an API URL. `$GITHUB_TOKEN` (or `$GH_TOKEN`) raises the anonymous rate
+/// limit; it goes to curl on stdin so it never appears in the process list
+fn get(url: &str) -> Option<String> {
+    let token = ["GITHUB_TOKEN", "GH_TOKEN"]
+        .iter()
+        .find_map(|var| std::env::var(var).ok().filter(|t| !t.is_empty()));
+    let mut cmd = Command::new("curl");
+    cmd.args(["-sf", "-m", "10", url]).stdout(Stdio::piped());
+    let output = match token {
+        Some(token) => {
+            let mut child = cmd.args(["-K", "-"]).stdin(Stdio::piped()).spawn().ok()?;
+            let config = format!("header = \"Authorization: Bearer {token}\"\n");
+            child.stdin.take()?.write_all(config.as_bytes()).ok()?;
+            child.wait_with_output().ok()?
+        }
+        None => cmd.output().ok()?,
+    };
+    output.status.success().then(|| String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// `owner/repo` from a repository name or URL
+pub fn parse_repo(package: &str) -> Option<(String, String)> {
+    let cleaned = package
+        .trim_start_matches("https://")
+        .trim_start_matches("http://")
+        .trim_start_matches("github.com/")
+        .trim_end_matches(".git")
+        .trim_end_matches('/');
+
+    let mut parts = cleaned.split('/');
+    let owner = parts.next().filter(|s| !s.is_empty())?;
+    let repo = parts.next().filter(|s| !s.is_empty())?;
+
+    // Ensure no extra path segments
+    if parts.next().is_some() {
+        return None;
+    }
+
+    Some((owner.to_string(), repo.to_string()))
+}
+
+fn parse_tags(json: &str) -> Option<String> {
+    let parsed: serde_json::Value = serde_json::from_str(json).ok()?;
+    let tags = parsed.as_array()?;
+
+    let names: Vec<&str> = tags
+        .iter()
+        .filter_map(|t| t.get("name")?.as_str())
+        .map(|name| name.strip_prefix('v').unwrap_or(name))
+        .collect();
+
+    // Tags come back in ref order, not version order: take the highest release,
+    // falling back to the first tag for repos without version-like tags
+    names
+        .iter()
+        .filter(|n| n.starts_with(|c: char| c.is_ascii_digit()) && !n.contains('-'))
+        .fold(None, |best: Option<&str>, n| match best {
+            Some(b) if !is_newer(b, n) => Some(b),
+            _ => Some(n),
+        })
+        .or_else(|| names.first().copied())
+        .map(String::from)
+}
+
+/// Tag names that look like releases (`1.2.0`, `v1.2.0`), minus prereleases
+fn parse_release_tags(json: &str) -> Option<Vec<String>> {
+    let parsed: Value = serde_json::from_str(json).ok()?;
+    Some(
+        parsed
+            .as_array()?
+            .iter()
+            .filter_map(|t| t.get("name")?.as_str())
+            .map(|name| name.strip_prefix('v').unwrap_or(name))
+            .filter(|n| n.starts_with(|c: char| c.is_ascii_digit()) && !n.contains('-'))
+            .map(String::from)
+            .collect(),
+    )
+}
+
+/// The highest published release, by version rather than API (creation) order.
+/// Drafts are always skipped, prereleases unless asked for
+fn parse_releases(json: &str, prereleases: bool) -> Option<String> {
+    let parsed: Value = serde_json::from_str(json).ok()?;
+    let versions = parsed
+        .as_array()?
+        .iter()
+        .filter(|r| r.get("draft").and_then(Value::as_bool) != Some(true))
+        .filter(|r| prereleases || r.get("prerelease").and_then(Value::as_bool) != Some(true))
+        .filter_map(|r| tag_version(r.get("tag_name")?.as_str()?));
+    highest_version(versions).map(String::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RELEASES: &str = r#"[
+        {"tag_name":"v2.41.0-rc.1","draft":false,"prerelease":true},
+        {"tag_name":"v2.42.0","draft":true,"prerelease":false},
+        {"tag_name":"v2.9.0","draft":false,"prerelease":false},
+        {"tag_name":"v2.40.1","draft":false,"prerelease":false},
+        {"tag_name":"nightly","draft":false,"prerelease":true}
+    ]"#;
+
+    #[test]
+    fn test_parse_releases() {
+        assert_eq!(parse_releases(RELEASES, false), Some("2.40.1".to_string()));
+        assert_eq!(parse_releases(RELEASES, true), Some("2.41.0-rc.1".to_string()));
+        assert_eq!(parse_releases("[]", false), None);
+    }
+
+    #[test]
+    fn test_github_source() {
+        let github = GithubSource;
+        assert_eq!(github.name(), "github");
+        assert_eq!(github.ecosystem(), Ecosystem::System);
+        assert!(!github.is_local());
+        assert_eq!(github.command("junegunn/fzf@pre"), Some("fzf".to_string()));
+        assert_eq!(
+            github.command("https://github.com/BurntSushi/ripgrep"),
+            Some("ripgrep".to_string())
+        );
+        assert_eq!(github.get_version("fzf"), None);
+    }
+
+    #[test]
+    fn test_parse_repo_simple() {
+        assert_eq!(
+            parse_repo("apple/swift-argument-parser"),
+            Some(("apple".to_string(), "swift-argument-parser".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_parse_repo_full_url() {
+        assert_eq!(
+            parse_repo("https://github.com/apple/swift-argument-parser"),
+            Some(("apple".to_string(), "swift-argument-parser".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_parse_repo_with_git_suffix() {
+        assert_eq!(
+            parse_repo("https://github.com/apple/swift-argument-parser.git"),
+            Some(("apple".to_string(), "swift-argument-parser".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_parse_repo_invalid() {
+        assert_eq!(parse_repo("invalid"), None);
+        assert_eq!(parse_repo(""), None);
+        assert_eq!(parse_repo("/repo"), None);
+        assert_eq!(parse_repo("owner/"), None);
+        assert_eq!(parse_repo("a/b/c"), None);
+    }
+
+    #[test]
+    fn test_parse_tags() {
+        let json = r#"[{"name":"1.3.0"},{"name":"1.2.0"}]"#;
+        assert_eq!(parse_tags(json), Some("1.3.0".to_string()));
+    }
+
+    #[test]
+    fn test_parse_release_tags() {
+        let json =
+            r#"[{"name":"v2.0.0-beta.1"},{"name":"v1.5.0"},{"name":"nightly"},{"name":"1.4.2"}]"#;
+        assert_eq!(parse_release_tags(json), Some(vec!["1.5.0".to_string(), "1.4.2".to_string()]));
+    }
+
+    #[test]
+    fn test_parse_tags_with_v_prefix() {
+        let json = r#"[{"name":"v2.0.0"},{"name":"v1.0.0"}]"#;
+        assert_eq!(parse_tags(json), Some("2.0.0".to_string()));
+    }
+
+    #[test]
+    fn test_parse_tags_unordered() {
+        let json =
+            r#"[{"name":"1.9.0"},{"name":"1.10.0"},{"name":"2.0.0-beta.1"},{"name":"nightly"}]"#;
+        assert_eq!(parse_tags(json), Some("1.10.0".to_string()));
+        assert_eq!(parse_tags(r#"[{"name":"nightly"}]"#), Some("nightly".to_string()));
+    }
+
+    #[test]
+    fn test_parse_tags_empty() {
+        let json = r"[]";
+        assert_eq!(parse_tags(json), None);
+    }
+}
@@ -0,0 +1,155 @@
+use super::github::split_prerelease;
+use super::{Ecosystem, Source, highest_version, tag_version};
+use serde_json::Value;
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+/// Tools published as GitLab releases: `group/project` on gitlab.com, or
+/// `gitlab.example.com/group/project` on a self-managed instance
+///
+/// `@pre` includes prereleases. Compared against the `project` command when it's on `$PATH`
+pub struct GitlabSource;
+
+impl Source for GitlabSource {
+    fn name(&self) -> &'static str {
+        "gitlab"
+    }
+
+    fn ecosystem(&self) -> Ecosystem {
+        Ecosystem::System
+    }
+
+    fn get_version(&self, package: &str) -> Option<String> {
+        let (project, prereleases) = split_prerelease(package);
+        let (host, path) = parse_project(project)?;
+        let url = format!(
+            "https://{host}/api/v4/projects/{}/releases?per_page=100",
+            urlencoding::encode(&path)
+        );
+        parse_releases(&get(&host, &url)?, prereleases)
+    }
+
+    fn command(&self, package: &str) -> Option<String> {
+        let (_, path) = parse_project(split_prerelease(package).0)?;
+        path.rsplit('/').next().map(String::from)
+    }
+}
+
+/// GET an API URL on `host`. `$GITLAB_TOKEN` gives access to private projects; it goes
+/// to curl on stdin so it never appears in the process list
+fn get(host: &str, url: &str) -> Option<String> {
+    let token = std::env::var("GITLAB_TOKEN")
+        .ok()
+        .filter(|t| !t.is_empty())
+        .filter(|_| token_host(host, std::env::var("GITLAB_HOST").ok().as_deref()));
+    let mut cmd = Command::new("curl");
+    cmd.args(["-sf", "-m", "10", url]).stdout(Stdio::piped());
+    let output = match token {
+        Some(token) => {
+            let mut child = cmd.args(["-K", "-"]).stdin(Stdio::piped()).spawn().ok()?;
+            let config = format!("header = \"PRIVATE-TOKEN: {token}\"\n");
+            child.stdin.take()?.write_all(config.as_bytes()).ok()?;
+            child.wait_with_output().ok()?
+        }
+        None => cmd.output().ok()?,
+    };
+    output.status.success().then(|| String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Whether the token may go to `host`: only gitlab.com or the instance `$GITLAB_HOST`
+/// names, never whatever host a package argument points at
+fn token_host(host: &str, configured: Option<&str>) -> bool {
+    let configured = configured.map(|c| {
+        c.trim_start_matches("https://").trim_start_matches("http://").trim_end_matches('/')
+    });
+    host.eq_ignore_ascii_case("gitlab.com")
+        || configured.is_some_and(|c| !c.is_empty() && host.eq_ignore_ascii_case(c))
+}
+
+/// The instance host and project path. A first segment with a dot is a host;
+/// projects can sit in nested groups (`group/subgroup/project`)
+fn parse_project(package: &str) -> Option<(String, String)> {
+    let cleaned = package
+        .trim_start_matches("https://")
+        .trim_start_matches("http://")
+        .trim_end_matches(".git")
+        .trim_end_matches('/');
+    let (host, path) = match cleaned.split_once('/') {
+        Some((host, path)) if host.contains('.') => (host, path),
+        _ => ("gitlab.com", cleaned),
+    };
+    let segments: Vec<&str> = path.split('/').collect();
+    if segments.len() < 2 || segments.iter().any(|s| s.is_empty()) {
+        return None;
+    }
+    Some((host.to_string(), path.to_string()))
+}
+
+/// The highest release, by version rather than release date. GitLab has no
+/// prerelease flag, so versions with a suffix (`2.0.0-rc.1`) count as prereleases;
+/// upcoming releases aren't out yet and are always skipped
+fn parse_releases(json: &str, prereleases: bool) -> Option<String> {
+    let parsed: Value = serde_json::from_str(json).ok()?;
+    let versions = parsed
+        .as_array()?
+        .iter()
+        .filter(|r| r.get("upcoming_release").and_then(Value::as_bool) != Some(true))
+        .filter_map(|r| tag_version(r.get("tag_name")?.as_str()?))
+        .filter(|v| prereleases || !v.contains('-'));
+    highest_version(versions).map(String::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_project() {
+        assert_eq!(
+            parse_project("gitlab-org/cli"),
+            Some(("gitlab.com".to_string(), "gitlab-org/cli".to_string()))
+        );
+        assert_eq!(
+            parse_project("https://gitlab.com/acme/tools/deployer.git"),
+            Some(("gitlab.com".to_string(), "acme/tools/deployer".to_string()))
+        );
+        assert_eq!(
+            parse_project("gitlab.example.com/ops/runner"),
+            Some(("gitlab.example.com".to_string(), "ops/runner".to_string()))
+        );
+        assert_eq!(parse_project("cli"), None);
+        assert_eq!(parse_project("gitlab.example.com/runner"), None);
+        assert_eq!(parse_project("acme//cli"), None);
+    }
+
+    #[test]
+    fn test_token_host() {
+        assert!(token_host("gitlab.com", None));
+        assert!(!token_host("gitlab.example.com", None));
+        assert!(token_host("gitlab.example.com", Some("gitlab.example.com")));
+        assert!(token_host("gitlab.example.com", Some("https://gitlab.example.com/")));
+        assert!(!token_host("evil.example.com", Some("gitlab.example.com")));
+        assert!(!token_host("gitlab.example.com", Some("")));
+    }
+
+    #[test]
+    fn test_parse_releases() {
+        let json = r#"[
+            {"tag_name":"v1.50.0","upcoming_release":true},
+            {"tag_name":"v1.49.0-rc.1","upcoming_release":false},
+            {"tag_name":"v1.9.0","upcoming_release":false},
+            {"tag_name":"v1.48.0","upcoming_release":false}
+        ]"#;
+        assert_eq!(parse_releases(json, false), Some("1.48.0".to_string()));
+        assert_eq!(parse_releases(json, true), Some("1.49.0-rc.1".to_string()));
+    }
+
+    #[test]
+    fn test_gitlab_source() {
+        let gitlab = GitlabSource;
+        assert_eq!(gitlab.name(), "gitlab");
+        assert_eq!(gitlab.ecosystem(), Ecosystem::System);
+        assert!(!gitlab.is_local());
+        assert_eq!(gitlab.command("gitlab-org/cli@pre"), Some("cli".to_string()));
+    }
+}
@@ -0,0 +1,164 @@
+use super::{Ecosystem, Source};
+use crate::cache;
+use std::process::Command;
+
+/// How many successive major versions to probe past the current one
+const MAX_MAJOR_PROBES: u64 = 10;
+
+pub struct GoSource;
+
+impl Source for GoSource {
+    fn name(&self) -> &'static str {
+        "go"
+    }
+
+    fn ecosystem(&self) -> Ecosystem {
+        Ecosystem::Go
+    }
+
+    fn get_version(&self, package: &str) -> Option<String> {
+        let version = fetch_latest(package)?;
+        Some(version.strip_prefix('v').unwrap_or(&version).to_string())
+    }
+
+    fn is_newer(&self, installed: &str, latest: &str) -> bool {
+        // Pseudo-versions (v1.2.4-0.20240101120000-abcdef123456) are semver
+        // pre-releases of the next patch, which plain numeric comparison gets wrong
+        match (parse_go_version(installed), parse_go_version(latest)) {
+            (Some(a), Some(b)) => a < b,
+            _ => crate::is_newer(installed, latest),
+        }
+    }
+
+    fn notes(&self, package: &str, use_cache: bool) -> Vec<String> {
+        // @latest never crosses into /vN module paths, so probe them explicitly, one
+        // at a time: majors are consecutive, so the first missing one ends the search
+        let (base, major) = split_major(package);
+        let mut newest = None;
+        for next in (major + 1)..=(major + MAX_MAJOR_PROBES) {
+            let path = major_path(base, next);
+            match probe_major(&path, use_cache) {
+                Some(version) => newest = Some(format!("{path} {version}")),
+                None => break,
+            }
+        }
+        newest.map(|m| format!("newer major available: {m}")).into_iter().collect()
+    }
+}
+
+fn fetch_latest(module: &str) -> Option<String> {
+    let url = format!("https://proxy.golang.org/{}/@latest", escape_module_path(module));
+    let output = Command::new("curl").args(["-sf", "-m", "10", &url]).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    parse_latest_response(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// `fetch_latest` for a major version path. Missing majors are cached too, as an
+/// empty entry, since nearly every probe chain ends on one
+fn probe_major(path: &str, use_cache: bool) -> Option<String> {
+    if use_cache && let Some(cached) = cache::get("go-major", path) {
+        return Some(cached).filter(|v| !v.is_empty());
+    }
+    let version = fetch_latest(path);
+    if use_cache {
+        cache::set("go-major", path, version.as_deref().unwrap_or_default());
+    }
+    version
+}
+
+fn parse_latest_response(json: &str) -> Option<String> {
+    let parsed: serde_json::Value = serde_json::from_str(json).ok()?;
+    parsed.get("Version")?.as_str().map(String::from)
+}
+
+/// Module proxy paths encode uppercase letters as `!` + lowercase
+fn escape_module_path(module: &str) -> String {
+    let mut escaped = String::with_capacity(module.len());
+    for c in module.chars() {
+        if c.is_ascii_uppercase() {
+            escaped.push('!');
+            escaped.push(c.to_ascii_lowercase());
+        } else {
+            escaped.push(c);
+        }
+    }
+    urlencoding::encode(&escaped).replace("%2F", "/").replace("%21", "!")
+}
+
+/// Split a module path into its major-less base and major version
+/// (`github.com/foo/bar/v3` -> (`github.com/foo/bar`, 3); gopkg.in uses `.v3`)
+fn split_major(module: &str) -> (&str, u64) {
+    let sep = if module.starts_with("gopkg.in/") { '.' } else { '/' };
+    if let Some((base, suffix)) = module.rsplit_once(sep)
+        && let Some(major) = suffix.strip_prefix('v').and_then(|n| n.parse().ok())
+    {
+        return (base, major);
+    }
+    (module, 1)
+}
+
+fn major_path(base: &str, major: u64) -> String {
+    let sep = if base.starts_with("gopkg.in/") { '.' } else { '/' };
+    format!("{base}{sep}v{major}")
+}
+
+fn parse_go_version(version: &str) -> Option<semver::Version> {
+    let version = version.strip_prefix('v').unwrap_or(version);
+    semver::Version::parse(version.trim_end_matches("+incompatible")).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_latest_response() {
+        let json = r#"{"Version":"v1.8.0","Time":"2023-11-04T00:00:00Z"}"#;
+        assert_eq!(parse_latest_response(json), Some("v1.8.0".to_string()));
+        assert_eq!(parse_latest_response("{}"), None);
+    }
+
+    #[test]
+    fn test_escape_module_path() {
+        assert_eq!(escape_module_path("github.com/spf13/cobra"), "github.com/spf13/cobra");
+        assert_eq!(
+            escape_module_path("github.com/BurntSushi/toml"),
+            "github.com/!burnt!sushi/toml"
+        );
+    }
+
+    #[test]
+    fn test_split_major() {
+        assert_eq!(split_major("github.com/foo/bar"), ("github.com/foo/bar", 1));
+        assert_eq!(split_major("github.com/foo/bar/v3"), ("github.com/foo/bar", 3));
+        assert_eq!(split_major("github.com/foo/version"), ("github.com/foo/version", 1));
+        assert_eq!(split_major("gopkg.in/yaml.v3"), ("gopkg.in/yaml", 3));
+        assert_eq!(major_path("github.com/foo/bar", 2), "github.com/foo/bar/v2");
+        assert_eq!(major_path("gopkg.in/yaml", 4), "gopkg.in/yaml.v4");
+    }
+
+    #[test]
+    fn test_is_newer_pseudo_versions() {
+        let go = GoSource;
+        // A pseudo-version sorts before the release it precedes
+        assert!(go.is_newer("1.2.4-0.20240101120000-abcdef123456", "1.2.4"));
+        assert!(!go.is_newer("1.2.4-0.20240101120000-abcdef123456", "1.2.3"));
+        // Untagged modules: later commit timestamps are newer
+        assert!(
+            go.is_newer("0.0.0-20230101000000-111111111111", "0.0.0-20240101000000-000000000000")
+        );
+        assert!(go.is_newer("0.0.0-20240101000000-abcdef123456", "0.1.0"));
+        assert!(go.is_newer("2.0.0+incompatible", "2.1.0+incompatible"));
+        assert!(!go.is_newer("1.8.0", "1.8.0"));
+    }
+
+    #[test]
+    fn test_go_source_properties() {
+        let go = GoSource;
+        assert_eq!(go.name(), "go");
+        assert_eq!(go.ecosystem(), Ecosystem::Go);
+        assert!(!go.is_local());
+    }
+}
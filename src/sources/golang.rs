@@ -0,0 +1,91 @@
+use super::{Ecosystem, Source, is_newer_within};
+use crate::is_newer;
+use std::process::Command;
+
+/// The Go toolchain itself, from the official release index
+pub struct GolangSource;
+
+impl Source for GolangSource {
+    fn name(&self) -> &'static str {
+        "golang"
+    }
+
+    fn ecosystem(&self) -> Ecosystem {
+        Ecosystem::System
+    }
+
+    fn get_version(&self, package: &str) -> Option<String> {
+        if !self.answers_for(package) {
+            return None;
+        }
+        let output = Command::new("curl")
+            .args(["-sf", "-m", "10", "https://go.dev/dl/?mode=json"])
+            .output()
+            .ok()?;
+        if !output.status.success() {
+            return None;
+        }
+        parse_go_releases(&String::from_utf8_lossy(&output.stdout))
+    }
+
+    fn answers_for(&self, package: &str) -> bool {
+        matches!(package, "go" | "golang")
+    }
+
+    /// `.tool-versions` pins like `1.22` follow the newest 1.22.x release
+    fn is_newer(&self, installed: &str, latest: &str) -> bool {
+        is_newer_within(installed, latest)
+    }
+}
+
+fn parse_go_releases(json: &str) -> Option<String> {
+    let parsed: serde_json::Value = serde_json::from_str(json).ok()?;
+    let releases = parsed.as_array()?;
+
+    releases
+        .iter()
+        .filter(|r| r.get("stable").and_then(serde_json::Value::as_bool) == Some(true))
+        .filter_map(|r| r.get("version")?.as_str()?.strip_prefix("go"))
+        .fold(None, |best: Option<&str>, v| match best {
+            Some(b) if !is_newer(b, v) => Some(b),
+            _ => Some(v),
+        })
+        .map(String::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_go_releases() {
+        let json = r#"[
+            {"version":"go1.23.2","stable":true,"files":[]},
+            {"version":"go1.22.8","stable":true,"files":[]},
+            {"version":"go1.24rc1","stable":false,"files":[]}
+        ]"#;
+        assert_eq!(parse_go_releases(json), Some("1.23.2".to_string()));
+    }
+
+    #[test]
+    fn test_parse_go_releases_unsorted() {
+        let json = r#"[{"version":"go1.22.8","stable":true},{"version":"go1.23.2","stable":true}]"#;
+        assert_eq!(parse_go_releases(json), Some("1.23.2".to_string()));
+    }
+
+    #[test]
+    fn test_parse_go_releases_empty() {
+        assert_eq!(parse_go_releases("[]"), None);
+        assert_eq!(parse_go_releases("not json"), None);
+    }
+
+    #[test]
+    fn test_golang_source_properties() {
+        let golang = GolangSource;
+        assert_eq!(golang.name(), "golang");
+        assert_eq!(golang.ecosystem(), Ecosystem::System);
+        assert!(!golang.is_local());
+        assert_eq!(golang.get_version("not-go"), None);
+        assert_eq!(golang.get_version("toolchain"), None);
+    }
+}
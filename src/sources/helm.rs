@@ -0,0 +1,222 @@
+use super::{Ecosystem, Source, matches_requirement, oci};
+use serde_yaml_ng::Value;
+use std::path::PathBuf;
+use std::process::Command;
+
+/// Helm charts from classic HTTP chart repositories (`index.yaml`) and OCI registries.
+///
+/// Packages are `<repo>/<chart>`: a repository URL
+/// (`https://charts.bitnami.com/bitnami/redis`, `oci://ghcr.io/acme/charts/api`)
+/// or a repository added with `helm repo add` (`bitnami/redis`)
+pub struct HelmSource;
+
+impl Source for HelmSource {
+    fn name(&self) -> &'static str {
+        "helm"
+    }
+
+    fn ecosystem(&self) -> Ecosystem {
+        Ecosystem::Helm
+    }
+
+    fn get_version(&self, package: &str) -> Option<String> {
+        let (repository, chart) = package.rsplit_once('/')?;
+        self.get_version_from(chart, repository)
+    }
+
+    fn get_version_from(&self, package: &str, registry: &str) -> Option<String> {
+        highest_stable(&self.get_versions_from(package, registry)?)
+    }
+
+    fn get_versions(&self, package: &str) -> Option<Vec<String>> {
+        let (repository, chart) = package.rsplit_once('/')?;
+        self.get_versions_from(chart, repository)
+    }
+
+    fn get_versions_from(&self, package: &str, registry: &str) -> Option<Vec<String>> {
+        let repository = registry.trim_end_matches('/');
+        let versions = if let Some(path) = repository.strip_prefix("oci://") {
+            let (host, path) = path.split_once('/').unwrap_or((path, ""));
+            let repo =
+                if path.is_empty() { package.to_string() } else { format!("{path}/{package}") };
+            chart_tags(oci::list_tags(host, &repo)?)
+        } else {
+            let url = if repository.contains("://") {
+                repository.to_string()
+            } else {
+                let name =
+                    repository.strip_prefix('@').or_else(|| repository.strip_prefix("alias:"));
+                repository_url(name.unwrap_or(repository))?
+            };
+            let index = curl(&format!("{}/index.yaml", url.trim_end_matches('/')))?;
+            parse_index(&index, package)?
+        };
+        Some(versions.into_iter().filter(|v| is_stable(v)).collect())
+    }
+
+    fn satisfies(&self, version: &str, requirement: &str) -> Option<bool> {
+        matches_requirement(version, requirement)
+    }
+}
+
+fn curl(url: &str) -> Option<String> {
+    let output = Command::new("curl").args(["-sfL", "-m", "30", url]).output().ok()?;
+    output.status.success().then(|| String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// URL of a repository added with `helm repo add`, from Helm's `repositories.yaml`
+fn repository_url(name: &str) -> Option<String> {
+    let content = std::fs::read_to_string(repositories_file()?).ok()?;
+    parse_repositories(&content, name)
+}
+
+/// `$HELM_REPOSITORY_CONFIG`, else `helm/repositories.yaml` in the XDG or platform
+/// config directory (`~/Library/Preferences` on macOS)
+fn repositories_file() -> Option<PathBuf> {
+    if let Some(path) = std::env::var_os("HELM_REPOSITORY_CONFIG") {
+        return Some(PathBuf::from(path));
+    }
+    let dir =
+        std::env::var_os("XDG_CONFIG_HOME").map(PathBuf::from).or_else(dirs::preference_dir)?;
+    Some(dir.join("helm").join("repositories.yaml"))
+}
+
+fn parse_repositories(content: &str, name: &str) -> Option<String> {
+    let parsed: Value = serde_yaml_ng::from_str(content).ok()?;
+    parsed.get("repositories")?.as_sequence()?.iter().find_map(|repo| {
+        (repo.get("name")?.as_str()? == name)
+            .then(|| repo.get("url")?.as_str().map(String::from))?
+    })
+}
+
+/// Non-deprecated releases of `chart` from a repository `index.yaml`
+fn parse_index(index: &str, chart: &str) -> Option<Vec<String>> {
+    let parsed: Value = serde_yaml_ng::from_str(index).ok()?;
+    let entries = parsed.get("entries")?.get(chart)?.as_sequence()?;
+    Some(
+        entries
+            .iter()
+            .filter(|e| !e.get("deprecated").and_then(Value::as_bool).unwrap_or(false))
+            .filter_map(|e| e.get("version")?.as_str().map(String::from))
+            .collect(),
+    )
+}
+
+/// OCI tags can't contain `+`, so Helm pushes `1.2.3+build` as `1.2.3_build`
+fn chart_tags(tags: Vec<String>) -> Vec<String> {
+    tags.into_iter().map(|t| t.replace('_', "+")).collect()
+}
+
+/// A release rather than a pre-release (`2.0.0-rc.1`) or a non-version tag
+fn is_stable(version: &str) -> bool {
+    version.trim_start_matches('v').starts_with(|c: char| c.is_ascii_digit())
+        && !version.split('+').next().unwrap_or(version).contains('-')
+}
+
+/// Highest release, skipping pre-releases and non-version tags
+fn highest_stable(versions: &[String]) -> Option<String> {
+    versions
+        .iter()
+        .filter(|v| is_stable(v))
+        .fold(None, |best: Option<&String>, v| match best {
+            Some(b) if !crate::is_newer(b, v) => Some(b),
+            _ => Some(v),
+        })
+        .cloned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INDEX: &str = r"apiVersion: v1
+entries:
+  redis:
+    - name: redis
+      version: 18.6.1
+      appVersion: 7.2.3
+      urls: [https://charts.example.com/redis-18.6.1.tgz]
+    - name: redis
+      version: 19.0.0-rc.1
+    - name: redis
+      version: 18.10.0
+    - name: redis
+      version: 17.3.2
+  legacy:
+    - name: legacy
+      version: 2.0.0
+      deprecated: true
+    - name: legacy
+      version: 1.4.0
+generated: 2024-01-15T10:00:00Z
+";
+
+    #[test]
+    fn test_parse_index() {
+        let highest = |chart| parse_index(INDEX, chart).and_then(|v| highest_stable(&v));
+        assert_eq!(highest("redis"), Some("18.10.0".to_string()));
+        assert_eq!(highest("legacy"), Some("1.4.0".to_string()));
+        assert_eq!(parse_index(INDEX, "missing"), None);
+    }
+
+    #[test]
+    fn test_chart_tags() {
+        let tags = vec!["1.2.0".to_string(), "1.10.0_build.5".to_string(), "latest".to_string()];
+        assert_eq!(highest_stable(&chart_tags(tags)), Some("1.10.0+build.5".to_string()));
+    }
+
+    #[test]
+    fn test_parse_repositories() {
+        let content = r"apiVersion: ''
+repositories:
+  - name: bitnami
+    url: https://charts.bitnami.com/bitnami
+  - name: local
+    url: http://localhost:8879/charts
+";
+        assert_eq!(
+            parse_repositories(content, "bitnami").as_deref(),
+            Some("https://charts.bitnami.com/bitnami")
+        );
+        assert_eq!(parse_repositories(content, "stable"), None);
+    }
+
+    /// Serve `body` once over HTTP on a free local port, returning the base URL
+    fn serve_once(body: &'static str) -> String {
+        use std::io::{Read, Write};
+        #[allow(clippy::unwrap_used)]
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().map(|a| a.port()).unwrap_or_default();
+        std::thread::spawn(move || {
+            if let Ok((mut stream, _)) = listener.accept() {
+                let mut request = [0; 1024];
+                let _ = stream.read(&mut request);
+                let _ = write!(
+                    stream,
+                    "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+            }
+        });
+        format!("http://127.0.0.1:{port}/charts")
+    }
+
+    #[test]
+    fn test_local_repository() {
+        let url = serve_once(INDEX);
+        assert_eq!(
+            HelmSource.get_versions_from("redis", &url),
+            Some(vec!["18.6.1".to_string(), "18.10.0".to_string(), "17.3.2".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_helm_source_properties() {
+        let helm = HelmSource;
+        assert_eq!(helm.name(), "helm");
+        assert_eq!(helm.ecosystem(), Ecosystem::Helm);
+        assert!(!helm.is_local());
+        assert_eq!(helm.satisfies("18.10.0", "^18.6.0"), Some(true));
+        assert_eq!(helm.satisfies("19.0.0", "~18.6"), Some(false));
+    }
+}
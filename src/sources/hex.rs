@@ -0,0 +1,90 @@
+use super::{Ecosystem, Source, matches_requirement};
+use std::process::Command;
+
+pub struct HexSource;
+
+impl Source for HexSource {
+    fn name(&self) -> &'static str {
+        "hex"
+    }
+
+    fn ecosystem(&self) -> Ecosystem {
+        Ecosystem::Beam
+    }
+
+    fn get_version(&self, package: &str) -> Option<String> {
+        parse_hex_response(&fetch(package)?)
+    }
+
+    fn get_versions(&self, package: &str) -> Option<Vec<String>> {
+        parse_hex_releases(&fetch(package)?)
+    }
+
+    fn satisfies(&self, version: &str, requirement: &str) -> Option<bool> {
+        matches_requirement(version, requirement)
+    }
+}
+
+fn fetch(package: &str) -> Option<String> {
+    let url = format!("https://hex.pm/api/packages/{}", urlencoding::encode(package));
+    let output = Command::new("curl").args(["-sf", "-m", "10", &url]).output().ok()?;
+    output.status.success().then(|| String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+fn parse_hex_response(json: &str) -> Option<String> {
+    let parsed: serde_json::Value = serde_json::from_str(json).ok()?;
+    parsed.get("latest_stable_version")?.as_str().map(String::from)
+}
+
+/// Stable versions from `"releases": [{"version": "1.7.14"}, ...]`
+fn parse_hex_releases(json: &str) -> Option<Vec<String>> {
+    let parsed: serde_json::Value = serde_json::from_str(json).ok()?;
+    let releases = parsed.get("releases")?.as_array()?;
+    Some(
+        releases
+            .iter()
+            .filter_map(|r| r.get("version")?.as_str())
+            .filter(|v| !v.contains('-'))
+            .map(String::from)
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_hex_response() {
+        let json =
+            r#"{"name":"phoenix","latest_version":"1.8.0-rc.0","latest_stable_version":"1.7.14"}"#;
+        assert_eq!(parse_hex_response(json), Some("1.7.14".to_string()));
+        assert_eq!(parse_hex_response("{}"), None);
+    }
+
+    #[test]
+    fn test_parse_hex_releases() {
+        let json =
+            r#"{"releases":[{"version":"1.8.0-rc.0"},{"version":"1.7.14"},{"version":"1.6.16"}]}"#;
+        assert_eq!(
+            parse_hex_releases(json),
+            Some(vec!["1.7.14".to_string(), "1.6.16".to_string()])
+        );
+        assert_eq!(parse_hex_releases("{}"), None);
+    }
+
+    #[test]
+    fn test_hex_requirements() {
+        let hex = HexSource;
+        assert_eq!(hex.satisfies("1.7.14", "~> 1.7"), Some(true));
+        assert_eq!(hex.satisfies("2.0.0", "~> 1.7"), Some(false));
+    }
+
+    #[test]
+    fn test_hex_source_properties() {
+        let hex = HexSource;
+        assert_eq!(hex.name(), "hex");
+        assert_eq!(hex.ecosystem(), Ecosystem::Beam);
+        assert!(!hex.is_local());
+    }
+}
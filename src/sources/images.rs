@@ -0,0 +1,96 @@
+use super::docker::{hub_repository, newest_tag};
+use super::{Ecosystem, Source};
+use serde_json::Value;
+use std::process::Command;
+
+/// Container images pulled locally, from the docker or podman CLI
+pub struct ImagesSource;
+
+impl Source for ImagesSource {
+    fn name(&self) -> &'static str {
+        "images"
+    }
+
+    fn ecosystem(&self) -> Ecosystem {
+        Ecosystem::Container
+    }
+
+    fn is_local(&self) -> bool {
+        true
+    }
+
+    fn get_version(&self, package: &str) -> Option<String> {
+        let (image, variant) = package.split_once("@*").unwrap_or((package, ""));
+        ["docker", "podman"].iter().find_map(|cli| {
+            let output = Command::new(cli).args(["images", "--format", "json"]).output().ok()?;
+            if !output.status.success() {
+                return None;
+            }
+            let tags = local_tags(&String::from_utf8_lossy(&output.stdout), image);
+            newest_tag(&tags, variant)
+        })
+    }
+}
+
+/// Tags of `image` from `images --format json`: docker prints one object per line
+/// with `Repository` and `Tag`, podman a single array with full `Names`
+fn local_tags(output: &str, image: &str) -> Vec<String> {
+    let objects: Vec<Value> = match serde_json::from_str::<Value>(output.trim()) {
+        Ok(Value::Array(items)) => items,
+        _ => output.lines().filter_map(|l| serde_json::from_str(l).ok()).collect(),
+    };
+
+    let wanted = hub_repository(image);
+    let references = objects.iter().flat_map(|object| {
+        let docker = object
+            .get("Repository")
+            .and_then(Value::as_str)
+            .zip(object.get("Tag").and_then(Value::as_str))
+            .map(|(repo, tag)| format!("{repo}:{tag}"));
+        let podman = object.get("Names").and_then(Value::as_array).into_iter().flatten();
+        docker.into_iter().chain(podman.filter_map(|n| n.as_str().map(String::from)))
+    });
+
+    references
+        .filter_map(|reference| {
+            let (repo, tag) = reference.rsplit_once(':').filter(|(_, t)| !t.contains('/'))?;
+            (hub_repository(repo) == wanted).then(|| tag.to_string())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_local_tags_docker() {
+        let output = r#"{"Containers":"N/A","ID":"a1","Repository":"redis","Tag":"7.2.4","Size":"138MB"}
+{"Containers":"N/A","ID":"b2","Repository":"redis","Tag":"7.0.15-alpine","Size":"30MB"}
+{"Containers":"N/A","ID":"c3","Repository":"bitnami/redis","Tag":"7.4.1","Size":"100MB"}
+{"Containers":"N/A","ID":"d4","Repository":"<none>","Tag":"<none>","Size":"1MB"}
+"#;
+        assert_eq!(local_tags(output, "redis"), vec!["7.2.4", "7.0.15-alpine"]);
+        assert_eq!(
+            newest_tag(&local_tags(output, "redis"), "-alpine").as_deref(),
+            Some("7.0.15-alpine")
+        );
+        assert_eq!(local_tags(output, "bitnami/redis"), vec!["7.4.1"]);
+    }
+
+    #[test]
+    fn test_local_tags_podman() {
+        let output = r#"[{"Id":"a1","Names":["docker.io/library/redis:7.2.4","docker.io/library/redis:7"]},
+            {"Id":"b2","Names":["ghcr.io/acme/api:1.0.0"]},{"Id":"c3","Dangling":true}]"#;
+        assert_eq!(local_tags(output, "redis"), vec!["7.2.4", "7"]);
+        assert_eq!(local_tags(output, "ghcr.io/acme/api"), vec!["1.0.0"]);
+    }
+
+    #[test]
+    fn test_images_source_properties() {
+        let images = ImagesSource;
+        assert_eq!(images.name(), "images");
+        assert_eq!(images.ecosystem(), Ecosystem::Container);
+        assert!(images.is_local());
+    }
+}
@@ -0,0 +1,107 @@
+use super::{Ecosystem, Source, is_newer_within};
+use crate::is_newer;
+use serde_json::Value;
+use std::process::Command;
+
+const API: &str = "https://api.adoptium.net/v3";
+
+/// Java, from the Eclipse Temurin (Adoptium) release API. `java@lts` is the newest
+/// LTS release and `java@17` the newest release of that feature line
+pub struct JavaSource;
+
+impl Source for JavaSource {
+    fn name(&self) -> &'static str {
+        "java"
+    }
+
+    fn ecosystem(&self) -> Ecosystem {
+        Ecosystem::System
+    }
+
+    fn get_version(&self, package: &str) -> Option<String> {
+        if !self.answers_for(package) {
+            return None;
+        }
+        let channel = package.split_once('@').map_or("", |(_, channel)| channel);
+        let feature = if let Ok(feature) = channel.parse::<u32>() {
+            feature
+        } else {
+            let releases = fetch(&format!("{API}/info/available_releases"))?;
+            parse_feature_release(&releases, channel == "lts")?
+        };
+        let assets = fetch(&format!("{API}/assets/latest/{feature}/hotspot?image_type=jdk"))?;
+        parse_latest_assets(&assets)
+    }
+
+    fn answers_for(&self, package: &str) -> bool {
+        matches!(package.split('@').next(), Some("java" | "javac" | "jdk" | "openjdk" | "temurin"))
+    }
+
+    /// `.tool-versions` pins like `21` follow the newest 21.x release
+    fn is_newer(&self, installed: &str, latest: &str) -> bool {
+        is_newer_within(installed, latest)
+    }
+}
+
+fn fetch(url: &str) -> Option<String> {
+    let output = Command::new("curl").args(["-sf", "-m", "10", url]).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    Some(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// `{"most_recent_feature_release": 23, "most_recent_lts": 21, ...}`
+fn parse_feature_release(json: &str, lts: bool) -> Option<u32> {
+    let parsed: Value = serde_json::from_str(json).ok()?;
+    let key = if lts { "most_recent_lts" } else { "most_recent_feature_release" };
+    u32::try_from(parsed.get(key)?.as_u64()?).ok()
+}
+
+/// One asset per platform, each with `{"version": {"semver": "21.0.5+11.0.LTS"}}`;
+/// the build metadata after `+` is dropped
+fn parse_latest_assets(json: &str) -> Option<String> {
+    let parsed: Value = serde_json::from_str(json).ok()?;
+    parsed
+        .as_array()?
+        .iter()
+        .filter_map(|a| a.get("version")?.get("semver")?.as_str()?.split('+').next())
+        .fold(None, |best: Option<&str>, v| match best {
+            Some(b) if !is_newer(b, v) => Some(b),
+            _ => Some(v),
+        })
+        .map(String::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_feature_release() {
+        let json = r#"{"available_lts_releases":[8,11,17,21],"available_releases":[8,11,17,21,22,23],
+            "most_recent_feature_release":23,"most_recent_feature_version":24,"most_recent_lts":21}"#;
+        assert_eq!(parse_feature_release(json, false), Some(23));
+        assert_eq!(parse_feature_release(json, true), Some(21));
+    }
+
+    #[test]
+    fn test_parse_latest_assets() {
+        let json = r#"[
+            {"binary":{"os":"linux"},"version":{"major":21,"semver":"21.0.5+11.0.LTS"}},
+            {"binary":{"os":"mac"},"version":{"major":21,"semver":"21.0.4+7.0.LTS"}},
+            {"binary":{"os":"windows"},"version":{"major":21,"semver":"21.0.5+11.0.LTS"}}
+        ]"#;
+        assert_eq!(parse_latest_assets(json).as_deref(), Some("21.0.5"));
+        assert_eq!(parse_latest_assets("[]"), None);
+    }
+
+    #[test]
+    fn test_java_source_properties() {
+        let java = JavaSource;
+        assert_eq!(java.name(), "java");
+        assert_eq!(java.ecosystem(), Ecosystem::System);
+        assert!(!java.is_local());
+        assert_eq!(java.get_version("maven"), None);
+    }
+}
@@ -25,6 +25,28 @@ impl Source for MavenSource {
         }
         parse_maven_response(&String::from_utf8_lossy(&output.stdout))
     }
+
+    /// `registry` lists repository URLs separated by whitespace, tried in order
+    /// before falling back to Maven Central (as Maven and Gradle do)
+    fn get_version_from(&self, package: &str, registry: &str) -> Option<String> {
+        let (group, artifact) = parse_maven_coordinates(package)?;
+        registry
+            .split_whitespace()
+            .find_map(|repo| {
+                let url = format!(
+                    "{}/{}/{}/maven-metadata.xml",
+                    repo.trim_end_matches('/'),
+                    group.replace('.', "/"),
+                    urlencoding::encode(artifact)
+                );
+                let output = Command::new("curl").args(["-sf", "-m", "10", &url]).output().ok()?;
+                if !output.status.success() {
+                    return None;
+                }
+                parse_maven_metadata(&String::from_utf8_lossy(&output.stdout))
+            })
+            .or_else(|| self.get_version(package))
+    }
 }
 
 fn parse_maven_coordinates(package: &str) -> Option<(&str, &str)> {
@@ -49,6 +71,24 @@ fn parse_maven_response(json: &str) -> Option<String> {
     first.get("latestVersion")?.as_str().map(String::from)
 }
 
+/// The release (or newest listed) version from a repository's maven-metadata.xml
+fn parse_maven_metadata(xml: &str) -> Option<String> {
+    let doc = roxmltree::Document::parse(xml).ok()?;
+    let versioning = doc.descendants().find(|n| n.has_tag_name("versioning"))?;
+    let child_text =
+        |name: &str| versioning.children().find(|n| n.has_tag_name(name))?.text().map(str::trim);
+    child_text("release")
+        .or_else(|| {
+            let versions = versioning.children().find(|n| n.has_tag_name("versions"))?;
+            versions
+                .children()
+                .filter(|n| n.has_tag_name("version"))
+                .filter_map(|n| n.text())
+                .next_back()
+        })
+        .map(String::from)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -86,6 +126,25 @@ mod tests {
         assert_eq!(parse_maven_response(json), None);
     }
 
+    #[test]
+    fn test_parse_maven_metadata() {
+        let xml = r"<metadata>
+  <groupId>com.acme</groupId>
+  <artifactId>lib</artifactId>
+  <versioning>
+    <latest>2.1.0-SNAPSHOT</latest>
+    <release>2.0.1</release>
+    <versions><version>1.0.0</version><version>2.0.1</version></versions>
+  </versioning>
+</metadata>";
+        assert_eq!(parse_maven_metadata(xml), Some("2.0.1".to_string()));
+
+        let xml = "<metadata><versioning><versions><version>1.0</version>\
+                   <version>1.1</version></versions></versioning></metadata>";
+        assert_eq!(parse_maven_metadata(xml), Some("1.1".to_string()));
+        assert_eq!(parse_maven_metadata("<metadata/>"), None);
+    }
+
     #[test]
     fn test_maven_source_properties() {
         let maven = MavenSource;
@@ -1,13 +1,33 @@
+mod actions;
 mod apt;
 mod brew;
 mod composer;
 mod conda;
+mod dart;
 mod docker;
+mod gem;
+mod git;
+mod github;
+mod gitlab;
+mod go;
+mod golang;
+mod helm;
+mod hex;
+mod images;
+mod java;
 mod maven;
+mod node;
 mod nuget;
+mod oci;
 mod path;
 mod pip;
+mod pubdev;
+mod pypi;
+mod python;
+mod ruby;
+mod rust;
 mod swift;
+mod terraform;
 mod uv;
 
 use serde::Deserialize;
@@ -36,15 +56,62 @@ pub enum Ecosystem {
     Container,
     Dotnet,
     Swift,
+    Terraform,
+    Helm,
+    Actions,
+    Git,
 }
 
 pub trait Source: Send + Sync {
     fn name(&self) -> &'static str;
     fn get_version(&self, package: &str) -> Option<String>;
+    /// Look up a package in an alternate registry (e.g. a private index URL).
+    /// Sources that only know their default registry return `None`.
+    fn get_version_from(&self, _package: &str, _registry: &str) -> Option<String> {
+        None
+    }
+    /// Every stable release of a package, for finding the newest one a declared
+    /// requirement allows. Sources that can't list releases return `None`.
+    fn get_versions(&self, _package: &str) -> Option<Vec<String>> {
+        None
+    }
+    /// `get_versions` against an alternate registry
+    fn get_versions_from(&self, _package: &str, _registry: &str) -> Option<Vec<String>> {
+        None
+    }
     fn is_local(&self) -> bool {
         false
     }
     fn ecosystem(&self) -> Ecosystem;
+    /// Whether `latest` is newer than `installed` under this source's versioning rules
+    fn is_newer(&self, installed: &str, latest: &str) -> bool {
+        crate::is_newer(installed, latest)
+    }
+    /// Whether `version` meets a declared requirement (e.g. `~> 1.7`), or `None`
+    /// if this source doesn't interpret requirements
+    fn satisfies(&self, _version: &str, _requirement: &str) -> Option<bool> {
+        None
+    }
+    /// Whether `version` has been republished upstream with different content than
+    /// the copy pulled locally or pinned by `digest` (e.g. a rebuilt image tag)
+    fn rebuilt(&self, _package: &str, _version: &str, _digest: Option<&str>) -> bool {
+        false
+    }
+    /// Extra information about a current package, e.g. a newer major version
+    /// published under a different name
+    fn notes(&self, _package: &str, _use_cache: bool) -> Vec<String> {
+        Vec::new()
+    }
+    /// The command on `$PATH` a package provides, for sources of standalone tools
+    /// whose releases are compared against the installed binary
+    fn command(&self, _package: &str) -> Option<String> {
+        None
+    }
+    /// Whether a lookup of `package` asks this source even when it isn't in the
+    /// precedence list, e.g. a runtime's release index for the runtime's own name
+    fn answers_for(&self, _package: &str) -> bool {
+        false
+    }
 }
 
 #[must_use]
@@ -57,6 +124,145 @@ pub fn extract_version_field(text: &str) -> Option<String> {
     text.lines().find_map(|l| l.strip_prefix("Version:").map(|v| v.trim().to_string()))
 }
 
+/// `is_newer` for partial pins that float within a release line (`v4`, `3.12`):
+/// only the components `installed` names are compared
+#[must_use]
+pub fn is_newer_within(installed: &str, latest: &str) -> bool {
+    let components = installed.split('.').count();
+    let latest: Vec<&str> = latest.split('.').take(components).collect();
+    crate::is_newer(installed, &latest.join("."))
+}
+
+/// The version in a release tag: `v1.2.3`, `tool-1.2.3` and `release-1.2.3` all
+/// give `1.2.3`. `None` for tags without one (`nightly`)
+#[must_use]
+pub fn tag_version(tag: &str) -> Option<&str> {
+    let version = tag.trim_start_matches(|c: char| !c.is_ascii_digit());
+    (!version.is_empty()).then_some(version)
+}
+
+/// The highest of `versions`, with a pre-release (`2.0.0-rc.1`) ranking below
+/// its release
+pub fn highest_version<'a>(versions: impl IntoIterator<Item = &'a str>) -> Option<&'a str> {
+    let newer = |a: &str, b: &str| {
+        let (a_core, a_pre) = a.split_once('-').map_or((a, None), |(c, p)| (c, Some(p)));
+        let (b_core, b_pre) = b.split_once('-').map_or((b, None), |(c, p)| (c, Some(p)));
+        crate::is_newer(a_core, b_core)
+            || (!crate::is_newer(b_core, a_core)
+                && match (a_pre, b_pre) {
+                    (Some(_), None) => true,
+                    (Some(a_pre), Some(b_pre)) => crate::is_newer(a_pre, b_pre),
+                    _ => false,
+                })
+    };
+    versions.into_iter().fold(None, |best, v| match best {
+        Some(b) if !newer(b, v) => Some(b),
+        _ => Some(v),
+    })
+}
+
+/// Check `version` against an Elixir, Ruby, Dart, Terraform or Helm style requirement.
+///
+/// Accepts forms like `~> 1.7`, `>= 1.0 and < 2.0`, `>= 1.2, < 2`, `>=3.0.0 <4.0.0`,
+/// `^3.5.0`, `~1.2`, `12.x` and `~> 1.0 or ~> 2.0`. Returns `None` if either side
+/// can't be parsed.
+#[must_use]
+pub fn matches_requirement(version: &str, requirement: &str) -> Option<bool> {
+    let version = numeric_parts(version)?;
+    requirement
+        .split(" or ")
+        .flat_map(|alt| alt.split("||"))
+        .map(|alt| {
+            alt.split(" and ")
+                .flat_map(|clause| clause.split(','))
+                .flat_map(split_clauses)
+                .map(|clause| matches_clause(&version, &clause))
+                .try_fold(true, |all, m| Some(all && m?))
+        })
+        .try_fold(false, |any, m| Some(any || m?))
+}
+
+/// Split space-separated clauses (`>=3.0.0 <4.0.0`), keeping an operator
+/// together with the version that follows it (`~> 1.7`)
+fn split_clauses(clause: &str) -> Vec<String> {
+    let mut clauses: Vec<String> = Vec::new();
+    let mut pending_op = false;
+    for token in clause.split_whitespace() {
+        match clauses.last_mut() {
+            Some(last) if pending_op => last.push_str(token),
+            _ => clauses.push(token.to_string()),
+        }
+        pending_op = token.chars().all(|c| "~^<>=!".contains(c));
+    }
+    clauses
+}
+
+fn matches_clause(version: &[u64], clause: &str) -> Option<bool> {
+    use std::cmp::Ordering::{Equal, Greater, Less};
+
+    if matches!(clause, "*" | "any") {
+        return Some(true);
+    }
+    // Wildcards: `1.2.x`, `2.x.x` and `1.*` allow anything under the prefix
+    if clause.ends_with(['x', 'X', '*']) && clause.contains('.') {
+        let prefix = clause.trim_end_matches(['x', 'X', '*', '.']).trim_start_matches('=');
+        let prefix = numeric_parts(prefix)?;
+        return Some(prefix.iter().enumerate().all(|(i, p)| version.get(i).unwrap_or(&0) == p));
+    }
+    let (op, target) = ["~>", ">=", "<=", "!=", "==", "^", "~", ">", "<", "="]
+        .iter()
+        .find_map(|op| clause.strip_prefix(op).map(|t| (*op, t)))
+        .unwrap_or(("==", clause));
+    let target = numeric_parts(target.trim())?;
+    let ord = compare_parts(version, &target);
+
+    Some(match op {
+        // Pessimistic: the last given component may grow, the ones before it may not
+        "~>" => {
+            let mut upper = target[..target.len().saturating_sub(1).max(1)].to_vec();
+            if let Some(last) = upper.last_mut() {
+                *last += 1;
+            }
+            ord != Less && compare_parts(version, &upper) == Less
+        }
+        // Caret: the leftmost non-zero component may not change
+        "^" => {
+            let pivot = target.iter().position(|&p| p != 0).unwrap_or(target.len() - 1);
+            let mut upper = target[..=pivot].to_vec();
+            upper[pivot] += 1;
+            ord != Less && compare_parts(version, &upper) == Less
+        }
+        // Tilde: patch updates, or minor updates when only a major is given
+        "~" => {
+            let mut upper = target[..target.len().min(2)].to_vec();
+            if let Some(last) = upper.last_mut() {
+                *last += 1;
+            }
+            ord != Less && compare_parts(version, &upper) == Less
+        }
+        ">=" => ord != Less,
+        "<=" => ord != Greater,
+        ">" => ord == Greater,
+        "<" => ord == Less,
+        "!=" => ord != Equal,
+        _ => ord == Equal,
+    })
+}
+
+/// Numeric release components, ignoring any pre-release or build suffix
+fn numeric_parts(version: &str) -> Option<Vec<u64>> {
+    let version = version.trim().trim_start_matches('v');
+    let release = version.split(['-', '+']).next()?;
+    release.split('.').map(|p| p.parse().ok()).collect::<Option<Vec<_>>>().filter(|p| !p.is_empty())
+}
+
+fn compare_parts(a: &[u64], b: &[u64]) -> std::cmp::Ordering {
+    (0..a.len().max(b.len()))
+        .map(|i| a.get(i).unwrap_or(&0).cmp(b.get(i).unwrap_or(&0)))
+        .find(|o| o.is_ne())
+        .unwrap_or(std::cmp::Ordering::Equal)
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // JSON API source - for registries with HTTP JSON APIs
 // ─────────────────────────────────────────────────────────────────────────────
@@ -117,16 +323,35 @@ fn extract_json_path(json: &str, path: &str) -> Option<String> {
 // Source registry - define all sources in ONE place
 // ─────────────────────────────────────────────────────────────────────────────
 
+pub use actions::ActionsSource;
 pub use apt::AptSource;
 pub use brew::BrewSource;
 pub use composer::ComposerSource;
 pub use conda::CondaSource;
-pub use docker::DockerSource;
+pub use dart::DartSource;
+pub use docker::{DockerSource, split_tag};
+pub use gem::GemSource;
+pub use git::GitSource;
+pub use github::GithubSource;
+pub use gitlab::GitlabSource;
+pub use go::GoSource;
+pub use golang::GolangSource;
+pub use helm::HelmSource;
+pub use hex::HexSource;
+pub use images::ImagesSource;
+pub use java::JavaSource;
 pub use maven::MavenSource;
+pub use node::NodeSource;
 pub use nuget::NuGetSource;
 pub use path::PathSource;
 pub use pip::PipSource;
+pub use pubdev::PubSource;
+pub use pypi::{PypiSource, normalize_name};
+pub use python::PythonSource;
+pub use ruby::RubySource;
+pub use rust::RustSource;
 pub use swift::SwiftSource;
+pub use terraform::TerraformSource;
 pub use uv::UvSource;
 
 // JSON API sources - no CLI needed, just HTTP
@@ -142,42 +367,12 @@ static CARGO: JsonApiSource = JsonApiSource {
     url_template: "https://crates.io/api/v1/crates/{}",
     version_path: "crate.max_stable_version",
 };
-static GO: JsonApiSource = JsonApiSource {
-    name: "go",
-    ecosystem: Ecosystem::Go,
-    url_template: "https://proxy.golang.org/{}/@latest",
-    version_path: "Version",
-};
-static GEM: JsonApiSource = JsonApiSource {
-    name: "gem",
-    ecosystem: Ecosystem::Ruby,
-    url_template: "https://rubygems.org/api/v1/gems/{}.json",
-    version_path: "version",
-};
-static HEX: JsonApiSource = JsonApiSource {
-    name: "hex",
-    ecosystem: Ecosystem::Beam,
-    url_template: "https://hex.pm/api/packages/{}",
-    version_path: "latest_stable_version",
-};
-static PUB: JsonApiSource = JsonApiSource {
-    name: "pub",
-    ecosystem: Ecosystem::Dart,
-    url_template: "https://pub.dev/api/packages/{}",
-    version_path: "latest.version",
-};
-static PYPI: JsonApiSource = JsonApiSource {
-    name: "pypi",
-    ecosystem: Ecosystem::Python,
-    url_template: "https://pypi.org/pypi/{}/json",
-    version_path: "info.version",
-};
 
-/// Source definitions: (name, `type_variant`, constructor, `is_local`, ecosystem)
-/// This is the SINGLE source of truth.
+/// Source definitions: (name, `type_variant`, constructor, `is_local`, `is_default`, ecosystem)
+/// This is the SINGLE source of truth. Sources that aren't default are only used when
+/// named: by prefix, `--source`, the config file, or a scanned dependency
 macro_rules! define_sources {
-    ($($name:literal, $variant:ident => $create:expr, $local:literal, $eco:expr);* $(;)?) => {
-        #[allow(dead_code)]
+    ($($name:literal, $variant:ident => $create:expr, $local:literal, $default:literal, $eco:expr);* $(;)?) => {
         pub fn all_sources() -> Vec<Box<dyn Source>> {
             vec![$(Box::new($create)),*]
         }
@@ -199,7 +394,10 @@ macro_rules! define_sources {
         }
 
         pub fn default_precedence() -> Vec<SourceType> {
-            vec![$(SourceType::$variant),*]
+            [$(($default, SourceType::$variant)),*]
+                .into_iter()
+                .filter_map(|(default, source)| default.then_some(source))
+                .collect()
         }
 
         #[cfg(test)]
@@ -210,24 +408,49 @@ macro_rules! define_sources {
 }
 
 define_sources! {
-    "path",     Path     => PathSource,     true,  Ecosystem::System;
-    "brew",     Brew     => BrewSource,     false, Ecosystem::System;
-    "apt",      Apt      => AptSource,      false, Ecosystem::System;
-    "npm",      Npm      => &NPM,           false, Ecosystem::Npm;
-    "uv",       Uv       => UvSource,       true,  Ecosystem::Python;
-    "pip",      Pip      => PipSource,      true,  Ecosystem::Python;
-    "pypi",     Pypi     => &PYPI,          false, Ecosystem::Python;
-    "conda",    Conda    => CondaSource,    false, Ecosystem::Python;
-    "go",       Go       => &GO,            false, Ecosystem::Go;
-    "cargo",    Cargo    => &CARGO,         false, Ecosystem::Cargo;
-    "gem",      Gem      => &GEM,           false, Ecosystem::Ruby;
-    "hex",      Hex      => &HEX,           false, Ecosystem::Beam;
-    "pub",      Pub      => &PUB,           false, Ecosystem::Dart;
-    "composer", Composer => ComposerSource, false, Ecosystem::Php;
-    "maven",    Maven    => MavenSource,    false, Ecosystem::Jvm;
-    "docker",   Docker   => DockerSource,   false, Ecosystem::Container;
-    "nuget",    Nuget    => NuGetSource,    false, Ecosystem::Dotnet;
-    "swift",    Swift    => SwiftSource,    false, Ecosystem::Swift;
+    "path",     Path     => PathSource,     true,  true,  Ecosystem::System;
+    "brew",     Brew     => BrewSource,     false, true,  Ecosystem::System;
+    "apt",      Apt      => AptSource,      false, true,  Ecosystem::System;
+    "npm",      Npm      => &NPM,           false, true,  Ecosystem::Npm;
+    "uv",       Uv       => UvSource,       true,  true,  Ecosystem::Python;
+    "pip",      Pip      => PipSource,      true,  true,  Ecosystem::Python;
+    "pypi",     Pypi     => PypiSource,     false, true,  Ecosystem::Python;
+    "conda",    Conda    => CondaSource,    false, true,  Ecosystem::Python;
+    "go",       Go       => GoSource,       false, true,  Ecosystem::Go;
+    "golang",   Golang   => GolangSource,   false, false, Ecosystem::System;
+    "cargo",    Cargo    => &CARGO,         false, true,  Ecosystem::Cargo;
+    "gem",      Gem      => GemSource,      false, true,  Ecosystem::Ruby;
+    "hex",      Hex      => HexSource,      false, true,  Ecosystem::Beam;
+    "pub",      Pub      => PubSource,      false, true,  Ecosystem::Dart;
+    "dart",     Dart     => DartSource,     false, false, Ecosystem::System;
+    "composer", Composer => ComposerSource, false, true,  Ecosystem::Php;
+    "maven",    Maven    => MavenSource,    false, true,  Ecosystem::Jvm;
+    "images",   Images   => ImagesSource,   true,  false, Ecosystem::Container;
+    "docker",   Docker   => DockerSource,   false, true,  Ecosystem::Container;
+    "nuget",    Nuget    => NuGetSource,    false, true,  Ecosystem::Dotnet;
+    "swift",    Swift    => SwiftSource,    false, true,  Ecosystem::Swift;
+    "terraform", Terraform => TerraformSource, false, false, Ecosystem::Terraform;
+    "helm",     Helm     => HelmSource,     false, false, Ecosystem::Helm;
+    "actions",  Actions  => ActionsSource,  false, false, Ecosystem::Actions;
+    "git",      Git      => GitSource,      false, false, Ecosystem::Git;
+    "node",     Node     => NodeSource,     false, false, Ecosystem::System;
+    "python",   Python   => PythonSource,   false, false, Ecosystem::System;
+    "ruby",     Ruby     => RubySource,     false, false, Ecosystem::System;
+    "rust",     Rust     => RustSource,     false, false, Ecosystem::System;
+    "java",     Java     => JavaSource,     false, false, Ecosystem::System;
+    "github",   Github   => GithubSource,   false, false, Ecosystem::System;
+    "gitlab",   Gitlab   => GitlabSource,   false, false, Ecosystem::System;
+}
+
+/// The sources to ask for `package` when none is named: the precedence list, then
+/// any source outside it that answers for the name (`latest node` asks nodejs.org)
+pub fn lookup_sources(precedence: &[SourceType], package: &str) -> Vec<Box<dyn Source>> {
+    let mut sources: Vec<Box<dyn Source>> = precedence.iter().map(SourceType::create).collect();
+    let listed = |source: &dyn Source| precedence.iter().any(|t| t.as_str() == source.name());
+    sources.extend(
+        all_sources().into_iter().filter(|s| s.answers_for(package) && !listed(s.as_ref())),
+    );
+    sources
 }
 
 #[cfg(test)]
@@ -241,6 +464,73 @@ mod tests {
         assert_eq!(extract_version(""), None);
     }
 
+    #[test]
+    fn test_tag_version() {
+        assert_eq!(tag_version("v1.2.3"), Some("1.2.3"));
+        assert_eq!(tag_version("jq-1.7.1"), Some("1.7.1"));
+        assert_eq!(tag_version("release-2.0.0-rc.1"), Some("2.0.0-rc.1"));
+        assert_eq!(tag_version("nightly"), None);
+    }
+
+    #[test]
+    fn test_highest_version() {
+        assert_eq!(highest_version(["1.9.0", "1.10.0", "1.2.0"]), Some("1.10.0"));
+        assert_eq!(highest_version(["2.0.0-rc.1", "2.0.0", "1.9.0"]), Some("2.0.0"));
+        assert_eq!(highest_version(["2.0.0-rc.2", "2.0.0-rc.10", "1.9.0"]), Some("2.0.0-rc.10"));
+        assert_eq!(highest_version(["2.0.0-beta.1", "1.9.0"]), Some("2.0.0-beta.1"));
+        assert_eq!(highest_version([]), None);
+    }
+
+    #[test]
+    fn test_matches_requirement_pessimistic() {
+        assert_eq!(matches_requirement("1.7.14", "~> 1.7"), Some(true));
+        assert_eq!(matches_requirement("2.0.0", "~> 1.7"), Some(false));
+        assert_eq!(matches_requirement("1.7.9", "~> 1.7.2"), Some(true));
+        assert_eq!(matches_requirement("1.8.0", "~> 1.7.2"), Some(false));
+        assert_eq!(matches_requirement("1.7.1", "~> 1.7.2"), Some(false));
+        assert_eq!(matches_requirement("5.2.0", "~> 5"), Some(true));
+        assert_eq!(matches_requirement("6.0.0", "~> 5"), Some(false));
+    }
+
+    #[test]
+    fn test_matches_requirement_compound() {
+        assert_eq!(matches_requirement("1.5.0", ">= 1.0.0 and < 2.0.0"), Some(true));
+        assert_eq!(matches_requirement("2.0.0", ">= 1.0.0 and < 2.0.0"), Some(false));
+        assert_eq!(matches_requirement("1.5.0", ">= 1.2, < 2"), Some(true));
+        assert_eq!(matches_requirement("2.1.0", "~> 1.0 or ~> 2.0"), Some(true));
+        assert_eq!(matches_requirement("2.10.0", "2.10.0"), Some(true));
+        assert_eq!(matches_requirement("2.11.0", "== 2.10.0"), Some(false));
+        assert_eq!(matches_requirement("1.0.0", "!= 1.0.0"), Some(false));
+    }
+
+    #[test]
+    fn test_matches_requirement_dart_ranges() {
+        assert_eq!(matches_requirement("3.5.4", ">=3.0.0 <4.0.0"), Some(true));
+        assert_eq!(matches_requirement("3.5.4", ">=2.12.0 <3.0.0"), Some(false));
+        assert_eq!(matches_requirement("3.9.0", "^3.5.0"), Some(true));
+        assert_eq!(matches_requirement("4.0.0", "^3.5.0"), Some(false));
+        assert_eq!(matches_requirement("0.3.0", "^0.2.1"), Some(false));
+        assert_eq!(matches_requirement("1.0.0", "any"), Some(true));
+    }
+
+    #[test]
+    fn test_matches_requirement_helm_ranges() {
+        assert_eq!(matches_requirement("1.2.9", "~1.2.3"), Some(true));
+        assert_eq!(matches_requirement("1.3.0", "~1.2.3"), Some(false));
+        assert_eq!(matches_requirement("1.9.0", "~1"), Some(true));
+        assert_eq!(matches_requirement("12.4.1", "12.x"), Some(true));
+        assert_eq!(matches_requirement("13.0.0", "12.x"), Some(false));
+        assert_eq!(matches_requirement("1.2.7", "1.2.*"), Some(true));
+        assert_eq!(matches_requirement("2.14.1", "2.x.x"), Some(true));
+        assert_eq!(matches_requirement("1.5.0", ">= 1.2.0 < 2.0.0"), Some(true));
+    }
+
+    #[test]
+    fn test_matches_requirement_unparseable() {
+        assert_eq!(matches_requirement("latest", "~> 1.0"), None);
+        assert_eq!(matches_requirement("1.0.0", "~> banana"), None);
+    }
+
     #[test]
     fn test_extract_json_path() {
         assert_eq!(
@@ -303,10 +593,57 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_default_precedence() {
+        let names: Vec<_> = default_precedence().iter().map(SourceType::as_str).collect();
+        assert_eq!(names.first(), Some(&"path"));
+        // Listing pulled images runs docker or podman, so it's only done on request
+        assert!(!names.contains(&"images"));
+        assert!(names.contains(&"docker"));
+        // Release feeds answer for arbitrary names; they're only asked when named
+        let feeds = [
+            "golang",
+            "dart",
+            "terraform",
+            "helm",
+            "actions",
+            "git",
+            "node",
+            "python",
+            "ruby",
+            "rust",
+            "java",
+            "github",
+            "gitlab",
+        ];
+        assert!(feeds.iter().all(|feed| !names.contains(feed)), "{names:?}");
+    }
+
+    #[test]
+    fn test_lookup_sources() {
+        let precedence = default_precedence();
+        let names = |package| {
+            let sources = lookup_sources(&precedence, package);
+            sources.iter().map(|s| s.name()).collect::<Vec<_>>()
+        };
+        assert_eq!(names("ripgrep").len(), precedence.len());
+        assert_eq!(names("node").last(), Some(&"node"));
+        assert_eq!(names("node@lts").last(), Some(&"node"));
+        assert_eq!(names("python3").last(), Some(&"python"));
+        assert_eq!(names("rust").last(), Some(&"rust"));
+        assert_eq!(names("go").last(), Some(&"golang"));
+        assert_eq!(names("java@17").last(), Some(&"java"));
+        assert!(!names("nodemon").contains(&"node"));
+
+        // A runtime already in the precedence list isn't asked twice
+        let sources = lookup_sources(&[SourceType::Path, SourceType::Node], "node");
+        assert_eq!(sources.len(), 2);
+    }
+
     #[test]
     fn test_source_by_name() {
         for (name, _, _) in expected_sources() {
-            assert!(source_by_name(name).is_some(), "missing: {}", name);
+            assert!(source_by_name(name).is_some(), "missing: {name}");
         }
         assert!(source_by_name("invalid").is_none());
     }
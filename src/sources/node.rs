@@ -0,0 +1,109 @@
+use super::{Ecosystem, Source, is_newer_within};
+use crate::is_newer;
+use serde_json::Value;
+use std::process::Command;
+
+/// Node.js itself, from the official release index. `node@lts` is the newest LTS
+/// release, `node@lts/iron` (or `node@iron`) the newest release of that LTS line
+/// and `node@20` the newest 20.x release
+pub struct NodeSource;
+
+impl Source for NodeSource {
+    fn name(&self) -> &'static str {
+        "node"
+    }
+
+    fn ecosystem(&self) -> Ecosystem {
+        Ecosystem::System
+    }
+
+    fn get_version(&self, package: &str) -> Option<String> {
+        if !self.answers_for(package) {
+            return None;
+        }
+        let channel = package.split_once('@').map_or("", |(_, channel)| channel);
+        let output = Command::new("curl")
+            .args(["-sf", "-m", "10", "https://nodejs.org/dist/index.json"])
+            .output()
+            .ok()?;
+        if !output.status.success() {
+            return None;
+        }
+        parse_node_releases(&String::from_utf8_lossy(&output.stdout), channel)
+    }
+
+    fn answers_for(&self, package: &str) -> bool {
+        matches!(package.split('@').next(), Some("node" | "nodejs"))
+    }
+
+    /// `.nvmrc` pins like `20` follow the newest 20.x release
+    fn is_newer(&self, installed: &str, latest: &str) -> bool {
+        is_newer_within(installed, latest)
+    }
+}
+
+/// The newest release in `channel`: empty for Current, `lts` for any LTS line,
+/// `lts/<codename>` or `<codename>` for one LTS line, `<major>` for one release line.
+/// Entries look like `{"version": "v20.11.0", "lts": "Iron"}`
+fn parse_node_releases(json: &str, channel: &str) -> Option<String> {
+    let parsed: Value = serde_json::from_str(json).ok()?;
+    let channel =
+        channel.strip_prefix("lts/").map_or(channel, |c| if c == "*" { "lts" } else { c });
+    let major = channel.parse::<u64>().is_ok().then(|| format!("{channel}."));
+
+    parsed
+        .as_array()?
+        .iter()
+        .filter(|r| {
+            let lts = r.get("lts").and_then(Value::as_str);
+            match channel {
+                "" => true,
+                "lts" => lts.is_some(),
+                _ if major.is_some() => true,
+                codename => lts.is_some_and(|l| l.eq_ignore_ascii_case(codename)),
+            }
+        })
+        .filter_map(|r| r.get("version")?.as_str()?.strip_prefix('v'))
+        .filter(|v| major.as_ref().is_none_or(|major| v.starts_with(major.as_str())))
+        .fold(None, |best: Option<&str>, v| match best {
+            Some(b) if !is_newer(b, v) => Some(b),
+            _ => Some(v),
+        })
+        .map(String::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INDEX: &str = r#"[
+        {"version":"v23.5.0","date":"2024-12-19","lts":false},
+        {"version":"v22.12.0","date":"2024-12-03","lts":"Jod"},
+        {"version":"v20.18.1","date":"2024-11-20","lts":"Iron"},
+        {"version":"v20.9.0","date":"2023-10-24","lts":"Iron"},
+        {"version":"v21.7.3","date":"2024-04-10","lts":false}
+    ]"#;
+
+    #[test]
+    fn test_parse_node_releases() {
+        assert_eq!(parse_node_releases(INDEX, "").as_deref(), Some("23.5.0"));
+        assert_eq!(parse_node_releases(INDEX, "lts").as_deref(), Some("22.12.0"));
+        assert_eq!(parse_node_releases(INDEX, "lts/*").as_deref(), Some("22.12.0"));
+        assert_eq!(parse_node_releases(INDEX, "lts/iron").as_deref(), Some("20.18.1"));
+        assert_eq!(parse_node_releases(INDEX, "iron").as_deref(), Some("20.18.1"));
+        assert_eq!(parse_node_releases(INDEX, "lts/argon"), None);
+        assert_eq!(parse_node_releases(INDEX, "21").as_deref(), Some("21.7.3"));
+        assert_eq!(parse_node_releases(INDEX, "2"), None);
+    }
+
+    #[test]
+    fn test_node_source_properties() {
+        let node = NodeSource;
+        assert_eq!(node.name(), "node");
+        assert_eq!(node.ecosystem(), Ecosystem::System);
+        assert!(!node.is_local());
+        assert_eq!(node.get_version("express"), None);
+        assert!(!node.is_newer("20", "20.18.1"));
+        assert!(node.is_newer("20.9.0", "20.18.1"));
+    }
+}
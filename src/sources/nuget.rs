@@ -1,4 +1,5 @@
 use super::{Ecosystem, Source};
+use std::cmp::Ordering;
 use std::process::Command;
 
 pub struct NuGetSource;
@@ -26,14 +27,67 @@ impl Source for NuGetSource {
 
         parse_nuget_versions(&String::from_utf8_lossy(&output.stdout))
     }
+
+    fn is_newer(&self, installed: &str, latest: &str) -> bool {
+        compare_versions(installed, latest) == Ordering::Less
+    }
 }
 
 fn parse_nuget_versions(json: &str) -> Option<String> {
     let parsed: serde_json::Value = serde_json::from_str(json).ok()?;
     let versions = parsed.get("versions")?.as_array()?;
 
-    // Filter out prereleases (contain '-') and get last (latest) stable version
-    versions.iter().filter_map(|v| v.as_str()).rfind(|v| !v.contains('-')).map(String::from)
+    // The index isn't guaranteed to be sorted, so pick the highest stable version
+    versions
+        .iter()
+        .filter_map(|v| v.as_str())
+        .filter(|v| !v.split('+').next().unwrap_or(v).contains('-'))
+        .max_by(|a, b| compare_versions(a, b))
+        .map(String::from)
+}
+
+/// Compare `NuGet` versions: up to four numeric parts, then `SemVer` 2 pre-release
+/// labels (case-insensitive), ignoring `+metadata`
+fn compare_versions(a: &str, b: &str) -> Ordering {
+    let split = |v: &str| {
+        let v = v.split('+').next().unwrap_or(v);
+        let (release, pre) = v.split_once('-').map_or((v, None), |(r, p)| (r, Some(p)));
+        let parts: Vec<u64> = release.split('.').map(|p| p.parse().unwrap_or(0)).collect();
+        (parts, pre.map(str::to_ascii_lowercase))
+    };
+    let ((a_parts, a_pre), (b_parts, b_pre)) = (split(a), split(b));
+
+    (0..4)
+        .map(|i| a_parts.get(i).unwrap_or(&0).cmp(b_parts.get(i).unwrap_or(&0)))
+        .find(|o| o.is_ne())
+        .unwrap_or_else(|| match (a_pre, b_pre) {
+            (None, None) => Ordering::Equal,
+            // A release sorts after its pre-releases
+            (None, Some(_)) => Ordering::Greater,
+            (Some(_), None) => Ordering::Less,
+            (Some(a), Some(b)) => compare_labels(&a, &b),
+        })
+}
+
+fn compare_labels(a: &str, b: &str) -> Ordering {
+    let (mut a, mut b) = (a.split('.'), b.split('.'));
+    loop {
+        let ord = match (a.next(), b.next()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(x), Some(y)) => match (x.parse::<u64>(), y.parse::<u64>()) {
+                (Ok(x), Ok(y)) => x.cmp(&y),
+                // Numeric identifiers sort before alphanumeric ones
+                (Ok(_), Err(_)) => Ordering::Less,
+                (Err(_), Ok(_)) => Ordering::Greater,
+                (Err(_), Err(_)) => x.cmp(y),
+            },
+        };
+        if ord.is_ne() {
+            return ord;
+        }
+    }
 }
 
 #[cfg(test)]
@@ -49,10 +103,29 @@ mod tests {
     #[test]
     fn test_parse_nuget_versions_with_prereleases() {
         let json = r#"{"versions":["1.0.0","2.0.0-beta","2.0.0-rc1","1.5.0"]}"#;
-        // Should skip prereleases, return 1.5.0 (last stable)
+        // Should skip prereleases, return 1.5.0 (highest stable)
         assert_eq!(parse_nuget_versions(json), Some("1.5.0".to_string()));
     }
 
+    #[test]
+    fn test_parse_nuget_versions_unsorted() {
+        let json = r#"{"versions":["10.0.0","9.0.1","2.0.0+build.5","10.0.0-preview.1"]}"#;
+        assert_eq!(parse_nuget_versions(json), Some("10.0.0".to_string()));
+    }
+
+    #[test]
+    fn test_compare_versions() {
+        assert_eq!(compare_versions("1.0.0", "1.0.0.0"), Ordering::Equal);
+        assert_eq!(compare_versions("1.0.0.1", "1.0.0"), Ordering::Greater);
+        assert_eq!(compare_versions("2.0.0-rc.1", "2.0.0"), Ordering::Less);
+        assert_eq!(compare_versions("2.0.0-rc.2", "2.0.0-RC.10"), Ordering::Less);
+        assert_eq!(compare_versions("2.0.0-1", "2.0.0-alpha"), Ordering::Less);
+        assert_eq!(compare_versions("2.0.0-beta", "2.0.0-beta.1"), Ordering::Less);
+        assert_eq!(compare_versions("1.0.0+abc", "1.0.0+def"), Ordering::Equal);
+        assert!(NuGetSource.is_newer("6.0.0-preview.7", "6.0.0"));
+        assert!(!NuGetSource.is_newer("13.0.3", "13.0.1"));
+    }
+
     #[test]
     fn test_parse_nuget_versions_only_prereleases() {
         let json = r#"{"versions":["1.0.0-alpha","1.0.0-beta"]}"#;
@@ -0,0 +1,325 @@
+//! OCI distribution API client for registries other than Docker Hub (ghcr.io,
+//! quay.io, gcr.io, private registries)
+
+use serde_json::Value;
+use std::collections::HashMap;
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+const MAX_PAGES: usize = 10;
+
+struct Response {
+    status: u16,
+    headers: Vec<(String, String)>,
+    body: String,
+}
+
+impl Response {
+    fn header(&self, name: &str) -> Option<&str> {
+        self.headers.iter().find(|(k, _)| k.eq_ignore_ascii_case(name)).map(|(_, v)| v.as_str())
+    }
+}
+
+/// Manifest media types a tag may resolve to; multi-platform indexes first, so the
+/// digest matches what `docker pull` records
+const MANIFEST_TYPES: &str = "application/vnd.oci.image.index.v1+json, \
+    application/vnd.docker.distribution.manifest.list.v2+json, \
+    application/vnd.docker.distribution.manifest.v2+json, \
+    application/vnd.oci.image.manifest.v1+json";
+
+/// Every tag of `repository` on `host`, authenticating via the registry's
+/// `WWW-Authenticate` challenge: anonymously, or with `~/.docker/config.json` credentials
+pub fn list_tags(host: &str, repository: &str) -> Option<Vec<String>> {
+    let base = base_url(host);
+    let mut url = Some(format!("{base}/v2/{repository}/tags/list?n=1000"));
+    let mut authorization: Option<String> = None;
+    let mut tags = Vec::new();
+
+    for _ in 0..MAX_PAGES {
+        let Some(page_url) = url.take() else { break };
+        let response = request(&page_url, host, repository, &mut authorization, false)?;
+        if response.status != 200 {
+            break;
+        }
+        tags.extend(parse_tags(&response.body)?);
+        url = response
+            .header("link")
+            .and_then(next_link)
+            .map(|path| if path.starts_with("http") { path } else { format!("{base}{path}") });
+    }
+    (!tags.is_empty()).then_some(tags)
+}
+
+/// The digest `reference` (a tag) currently points to, from a manifest `HEAD`
+pub fn manifest_digest(host: &str, repository: &str, reference: &str) -> Option<String> {
+    let url = format!("{}/v2/{repository}/manifests/{reference}", base_url(host));
+    let response = request(&url, host, repository, &mut None, true)?;
+    (response.status == 200)
+        .then(|| response.header("docker-content-digest").map(String::from))
+        .flatten()
+}
+
+fn base_url(host: &str) -> String {
+    // Local registries are usually plain HTTP
+    let local = host.starts_with("localhost") || host.starts_with("127.0.0.1");
+    format!("{}://{host}", if local { "http" } else { "https" })
+}
+
+/// A request that answers the first authentication challenge and keeps the
+/// resulting authorization for later requests
+fn request(
+    url: &str,
+    host: &str,
+    repository: &str,
+    authorization: &mut Option<String>,
+    head: bool,
+) -> Option<Response> {
+    let response = get(url, authorization.as_deref(), head)?;
+    if response.status != 401 || authorization.is_some() {
+        return Some(response);
+    }
+    let challenge = parse_challenge(response.header("www-authenticate")?)?;
+    *authorization = Some(authorize(&challenge, host, repository)?);
+    get(url, authorization.as_deref(), head)
+}
+
+/// An `Authorization` header value answering a `Bearer` or `Basic` challenge
+fn authorize(
+    challenge: &(String, HashMap<String, String>),
+    host: &str,
+    repo: &str,
+) -> Option<String> {
+    let (scheme, params) = challenge;
+    let basic = credentials(host).map(|c| format!("Basic {c}"));
+    if scheme.eq_ignore_ascii_case("basic") {
+        return basic;
+    }
+
+    let realm = params.get("realm")?;
+    let scope = params.get("scope").cloned().unwrap_or_else(|| format!("repository:{repo}:pull"));
+    let service = params
+        .get("service")
+        .map(|s| format!("&service={}", urlencoding::encode(s)))
+        .unwrap_or_default();
+    let url = format!("{realm}?scope={}{service}", urlencoding::encode(&scope));
+    let response = get(&url, basic.as_deref(), false)?;
+    let json: Value = serde_json::from_str(&response.body).ok()?;
+    let token = json.get("token").or_else(|| json.get("access_token"))?.as_str()?;
+    Some(format!("Bearer {token}"))
+}
+
+/// GET (or HEAD) with headers included in the output. The authorization header
+/// goes to curl on stdin so credentials never appear in the process list
+fn get(url: &str, authorization: Option<&str>, head: bool) -> Option<Response> {
+    let mut cmd = Command::new("curl");
+    cmd.args(["-s", "-m", "10", if head { "-I" } else { "-i" }, url]).stdout(Stdio::piped());
+    if head {
+        cmd.args(["-H", &format!("Accept: {MANIFEST_TYPES}")]);
+    }
+    let Some(authorization) = authorization else {
+        return parse_response(&String::from_utf8_lossy(&cmd.output().ok()?.stdout));
+    };
+
+    let mut child = cmd.args(["-K", "-"]).stdin(Stdio::piped()).spawn().ok()?;
+    let config = format!("header = \"Authorization: {authorization}\"\n");
+    child.stdin.take()?.write_all(config.as_bytes()).ok()?;
+    let output = child.wait_with_output().ok()?;
+    parse_response(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// Split `curl -i` output into status, headers and body, skipping interim
+/// responses like `100 Continue` or a proxy's `Connection established`
+fn parse_response(raw: &str) -> Option<Response> {
+    let mut rest = raw;
+    loop {
+        let (head, body) = rest.split_once("\r\n\r\n").or_else(|| rest.split_once("\n\n"))?;
+        if body.starts_with("HTTP/") {
+            rest = body;
+            continue;
+        }
+        let mut lines = head.lines();
+        let status = lines.next()?.split_whitespace().nth(1)?.parse().ok()?;
+        let headers = lines
+            .filter_map(|l| l.split_once(':'))
+            .map(|(k, v)| (k.trim().to_string(), v.trim().to_string()))
+            .collect();
+        return Some(Response { status, headers, body: body.to_string() });
+    }
+}
+
+/// `Bearer realm="https://ghcr.io/token",service="ghcr.io",scope="repository:x:pull"`
+fn parse_challenge(header: &str) -> Option<(String, HashMap<String, String>)> {
+    let header = header.trim();
+    let (scheme, rest) = header.split_once(' ').unwrap_or((header, ""));
+    let mut params = HashMap::new();
+    let mut rest = rest.trim();
+    while let Some((key, value)) = rest.split_once('=') {
+        let key = key.trim().trim_start_matches(',').trim().to_ascii_lowercase();
+        let value = value.trim_start();
+        let (value, remaining) = match value.strip_prefix('"') {
+            Some(quoted) => quoted.split_once('"')?,
+            None => value.split_once(',').unwrap_or((value, "")),
+        };
+        params.insert(key, value.to_string());
+        rest = remaining.trim_start_matches([',', ' ']);
+    }
+    Some((scheme.to_string(), params))
+}
+
+fn parse_tags(json: &str) -> Option<Vec<String>> {
+    let parsed: Value = serde_json::from_str(json).ok()?;
+    // Empty repositories report `"tags": null`
+    Some(
+        parsed
+            .get("tags")?
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|t| t.as_str().map(String::from))
+            .collect(),
+    )
+}
+
+/// Target of a `Link: </v2/x/tags/list?last=y>; rel="next"` header
+fn next_link(header: &str) -> Option<String> {
+    header
+        .split(',')
+        .find(|link| link.contains("rel=\"next\"") || link.contains("rel=next"))
+        .and_then(|link| Some(link.split_once('<')?.1.split_once('>')?.0.to_string()))
+}
+
+/// Base64 `user:password` for `host` from the Docker CLI config: a per-registry
+/// credential helper, a stored `auth`, or the default credential store
+fn credentials(host: &str) -> Option<String> {
+    let dir = std::env::var_os("DOCKER_CONFIG")
+        .map(std::path::PathBuf::from)
+        .or_else(|| dirs::home_dir().map(|h| h.join(".docker")))?;
+    let config: Value =
+        serde_json::from_str(&std::fs::read_to_string(dir.join("config.json")).ok()?).ok()?;
+
+    let helper = config.get("credHelpers").and_then(|h| h.get(host)).and_then(Value::as_str);
+    helper
+        .and_then(|h| credential_helper(h, host))
+        .or_else(|| stored_auth(&config, host))
+        .or_else(|| credential_helper(config.get("credsStore")?.as_str()?, host))
+}
+
+/// `auths` entries are keyed by host, sometimes with a scheme or path
+fn stored_auth(config: &Value, host: &str) -> Option<String> {
+    let auths = config.get("auths")?.as_object()?;
+    auths
+        .iter()
+        .find(|(key, _)| {
+            let key = key.split_once("://").map_or(key.as_str(), |(_, rest)| rest);
+            key.split('/').next() == Some(host)
+        })
+        .and_then(|(_, entry)| entry.get("auth")?.as_str())
+        .filter(|auth| !auth.is_empty())
+        .map(String::from)
+}
+
+/// `docker-credential-<helper> get`, which reads the host on stdin
+fn credential_helper(helper: &str, host: &str) -> Option<String> {
+    let mut child = Command::new(format!("docker-credential-{helper}"))
+        .arg("get")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .ok()?;
+    child.stdin.take()?.write_all(host.as_bytes()).ok()?;
+    let output = child.wait_with_output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let json: Value = serde_json::from_slice(&output.stdout).ok()?;
+    let user = json.get("Username")?.as_str()?;
+    let secret = json.get("Secret")?.as_str()?;
+    Some(base64_encode(format!("{user}:{secret}").as_bytes()))
+}
+
+fn base64_encode(input: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut out = String::new();
+    for chunk in input.chunks(3) {
+        let b = [chunk[0], *chunk.get(1).unwrap_or(&0), *chunk.get(2).unwrap_or(&0)];
+        let n = (u32::from(b[0]) << 16) | (u32::from(b[1]) << 8) | u32::from(b[2]);
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(char::from(ALPHABET[(n >> (18 - 6 * i)) as usize & 63]));
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_challenge() {
+        let (scheme, params) = parse_challenge(
+            r#"Bearer realm="https://ghcr.io/token",service="ghcr.io",scope="repository:acme/app:pull""#,
+        )
+        .unwrap_or_default();
+        assert_eq!(scheme, "Bearer");
+        assert_eq!(params.get("realm").map(String::as_str), Some("https://ghcr.io/token"));
+        assert_eq!(params.get("service").map(String::as_str), Some("ghcr.io"));
+        assert_eq!(params.get("scope").map(String::as_str), Some("repository:acme/app:pull"));
+
+        let (scheme, params) = parse_challenge(r#"Basic realm="Registry""#).unwrap_or_default();
+        assert_eq!(scheme, "Basic");
+        assert_eq!(params.get("realm").map(String::as_str), Some("Registry"));
+    }
+
+    #[test]
+    fn test_parse_response() {
+        let raw = "HTTP/1.1 100 Continue\r\n\r\nHTTP/2 401\r\nwww-authenticate: Bearer realm=\"x\"\r\n\
+                   content-type: application/json\r\n\r\n{\"errors\":[]}";
+        let response = parse_response(raw).unwrap_or(Response {
+            status: 0,
+            headers: Vec::new(),
+            body: String::new(),
+        });
+        assert_eq!(response.status, 401);
+        assert_eq!(response.header("WWW-Authenticate"), Some("Bearer realm=\"x\""));
+        assert_eq!(response.body, "{\"errors\":[]}");
+    }
+
+    #[test]
+    fn test_parse_tags_and_links() {
+        assert_eq!(
+            parse_tags(r#"{"name":"acme/app","tags":["1.0.0","1.1.0"]}"#),
+            Some(vec!["1.0.0".to_string(), "1.1.0".to_string()])
+        );
+        assert_eq!(parse_tags(r#"{"name":"acme/app","tags":null}"#), Some(Vec::new()));
+        assert_eq!(
+            next_link(r#"</v2/acme/app/tags/list?last=1.1.0&n=1000>; rel="next""#).as_deref(),
+            Some("/v2/acme/app/tags/list?last=1.1.0&n=1000")
+        );
+        assert_eq!(next_link(r#"</v2/x>; rel="prev""#), None);
+    }
+
+    #[test]
+    fn test_stored_auth() {
+        let config: Value = serde_json::from_str(
+            r#"{"auths":{"https://index.docker.io/v1/":{"auth":"aHViOnNlY3JldA=="},
+                         "ghcr.io":{"auth":"dXNlcjp0b2tlbg=="},
+                         "quay.io":{}}}"#,
+        )
+        .unwrap_or_default();
+        assert_eq!(stored_auth(&config, "ghcr.io").as_deref(), Some("dXNlcjp0b2tlbg=="));
+        assert_eq!(stored_auth(&config, "index.docker.io").as_deref(), Some("aHViOnNlY3JldA=="));
+        assert_eq!(stored_auth(&config, "quay.io"), None);
+    }
+
+    #[test]
+    fn test_base64_encode() {
+        assert_eq!(base64_encode(b"user:token"), "dXNlcjp0b2tlbg==");
+        assert_eq!(base64_encode(b"hub:secret"), "aHViOnNlY3JldA==");
+        assert_eq!(base64_encode(b"abc"), "YWJj");
+    }
+}
@@ -1,5 +1,5 @@
 use super::{Ecosystem, Source, extract_version};
-use std::process::{Command, Output};
+use std::process::{Command, Output, Stdio};
 use std::time::{Duration, Instant};
 
 /// Timeout for external command execution (5 seconds)
@@ -10,7 +10,8 @@ const POLL_INTERVAL: Duration = Duration::from_millis(10);
 
 /// Run a command with a timeout. Returns None if the command times out or fails.
 fn run_with_timeout(mut cmd: Command, timeout: Duration) -> Option<Output> {
-    let mut child = cmd.spawn().ok()?;
+    let mut child =
+        cmd.stdin(Stdio::null()).stdout(Stdio::piped()).stderr(Stdio::piped()).spawn().ok()?;
     let start = Instant::now();
 
     loop {
@@ -36,6 +37,37 @@ fn run_with_timeout(mut cmd: Command, timeout: Duration) -> Option<Output> {
     }
 }
 
+/// Runtimes whose command isn't named after them
+const COMMANDS: &[(&str, &str)] = &[
+    ("nodejs", "node"),
+    ("python", "python3"),
+    ("rust", "rustc"),
+    ("golang", "go"),
+    ("jdk", "java"),
+    ("openjdk", "java"),
+    ("temurin", "java"),
+];
+
+/// The command to run for `package`. A release channel (`node@lts`) is dropped, so
+/// the installed runtime is compared against that channel
+fn command_for(package: &str) -> Option<&str> {
+    let exists = |command: &str| {
+        Command::new("which").arg(command).output().is_ok_and(|o| o.status.success())
+    };
+    let name = package.split_once('@').map_or(package, |(name, _)| name);
+    if name.is_empty() {
+        return None;
+    }
+    if exists(name) {
+        return Some(name);
+    }
+    COMMANDS
+        .iter()
+        .find(|(alias, _)| *alias == name)
+        .map(|(_, command)| *command)
+        .filter(|c| exists(c))
+}
+
 pub struct PathSource;
 
 impl Source for PathSource {
@@ -51,10 +83,10 @@ impl Source for PathSource {
 
     fn get_version(&self, package: &str) -> Option<String> {
         // Check if command exists (which is fast, no timeout needed)
-        Command::new("which").arg(package).output().ok().filter(|o| o.status.success())?;
+        let command = command_for(package)?;
 
         for flag in ["--version", "-version", "version", "-V"] {
-            let mut cmd = Command::new(package);
+            let mut cmd = Command::new(command);
             cmd.arg(flag);
             if let Some(output) = run_with_timeout(cmd, COMMAND_TIMEOUT) {
                 let stdout = String::from_utf8_lossy(&output.stdout);
@@ -75,8 +107,10 @@ mod tests {
     #[test]
     fn test_run_with_timeout_fast_command() {
         // `echo` should complete well within timeout
-        let output = run_with_timeout(Command::new("echo"), Duration::from_secs(5));
-        assert!(output.is_some());
+        let mut cmd = Command::new("echo");
+        cmd.arg("1.2.3");
+        let output = run_with_timeout(cmd, Duration::from_secs(5));
+        assert_eq!(output.map(|o| o.stdout), Some(b"1.2.3\n".to_vec()));
     }
 
     #[test]
@@ -91,11 +125,18 @@ mod tests {
         assert!(output.is_none(), "Should have timed out");
         assert!(
             elapsed < Duration::from_secs(1),
-            "Should have timed out quickly, took {:?}",
-            elapsed
+            "Should have timed out quickly, took {elapsed:?}"
         );
     }
 
+    #[test]
+    fn test_command_for() {
+        assert_eq!(command_for("sh"), Some("sh"));
+        assert_eq!(command_for("sh@lts"), Some("sh"));
+        assert_eq!(command_for("@types/node"), None);
+        assert_eq!(command_for("definitely-not-a-command-xyz"), None);
+    }
+
     #[test]
     fn test_path_source_properties() {
         let source = PathSource;
@@ -16,7 +16,7 @@ impl Source for PipSource {
 
     fn get_version(&self, package: &str) -> Option<String> {
         let pip = ["pip", "pip3"].into_iter().find(|cmd| {
-            Command::new("which").arg(cmd).output().map(|o| o.status.success()).unwrap_or(false)
+            Command::new("which").arg(cmd).output().is_ok_and(|o| o.status.success())
         })?;
 
         // Only check locally installed packages
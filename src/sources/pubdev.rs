@@ -0,0 +1,87 @@
+use super::{Ecosystem, Source, matches_requirement};
+use std::process::Command;
+
+/// Dart and Flutter packages on pub.dev
+pub struct PubSource;
+
+impl Source for PubSource {
+    fn name(&self) -> &'static str {
+        "pub"
+    }
+
+    fn ecosystem(&self) -> Ecosystem {
+        Ecosystem::Dart
+    }
+
+    fn get_version(&self, package: &str) -> Option<String> {
+        parse_pub_response(&fetch(package)?)
+    }
+
+    fn get_versions(&self, package: &str) -> Option<Vec<String>> {
+        parse_pub_versions(&fetch(package)?)
+    }
+
+    fn satisfies(&self, version: &str, requirement: &str) -> Option<bool> {
+        matches_requirement(version, requirement)
+    }
+}
+
+fn fetch(package: &str) -> Option<String> {
+    let url = format!("https://pub.dev/api/packages/{}", urlencoding::encode(package));
+    let output = Command::new("curl").args(["-sf", "-m", "10", &url]).output().ok()?;
+    output.status.success().then(|| String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+fn parse_pub_response(json: &str) -> Option<String> {
+    let parsed: serde_json::Value = serde_json::from_str(json).ok()?;
+    parsed.get("latest")?.get("version")?.as_str().map(String::from)
+}
+
+/// Stable versions from `"versions": [{"version": "1.2.2", ...}, ...]`, minus retracted ones
+fn parse_pub_versions(json: &str) -> Option<Vec<String>> {
+    let parsed: serde_json::Value = serde_json::from_str(json).ok()?;
+    let versions = parsed.get("versions")?.as_array()?;
+    Some(
+        versions
+            .iter()
+            .filter(|v| v.get("retracted").and_then(serde_json::Value::as_bool) != Some(true))
+            .filter_map(|v| v.get("version")?.as_str())
+            .filter(|v| !v.contains('-'))
+            .map(String::from)
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_pub_response() {
+        let json = r#"{"name":"http","latest":{"version":"1.2.2","pubspec":{}},"versions":[]}"#;
+        assert_eq!(parse_pub_response(json), Some("1.2.2".to_string()));
+        assert_eq!(parse_pub_response(r#"{"name":"http"}"#), None);
+    }
+
+    #[test]
+    fn test_parse_pub_versions() {
+        let json = r#"{"versions":[{"version":"1.1.0"},{"version":"1.2.0","retracted":true},
+            {"version":"1.2.2"},{"version":"2.0.0-dev.1"}]}"#;
+        assert_eq!(parse_pub_versions(json), Some(vec!["1.1.0".to_string(), "1.2.2".to_string()]));
+    }
+
+    #[test]
+    fn test_pub_requirements() {
+        let pub_dev = PubSource;
+        assert_eq!(pub_dev.satisfies("1.2.2", "^1.1.0"), Some(true));
+        assert_eq!(pub_dev.satisfies("2.0.0", "^1.1.0"), Some(false));
+    }
+
+    #[test]
+    fn test_pub_source_properties() {
+        let pub_dev = PubSource;
+        assert_eq!(pub_dev.name(), "pub");
+        assert_eq!(pub_dev.ecosystem(), Ecosystem::Dart);
+        assert!(!pub_dev.is_local());
+    }
+}
@@ -0,0 +1,117 @@
+use super::{Ecosystem, Source};
+use crate::is_newer;
+use std::process::Command;
+
+pub struct PypiSource;
+
+impl Source for PypiSource {
+    fn name(&self) -> &'static str {
+        "pypi"
+    }
+
+    fn ecosystem(&self) -> Ecosystem {
+        Ecosystem::Python
+    }
+
+    fn get_version(&self, package: &str) -> Option<String> {
+        let url = format!("https://pypi.org/pypi/{}/json", urlencoding::encode(package));
+        let output = Command::new("curl").args(["-sf", "-m", "10", &url]).output().ok()?;
+        if !output.status.success() {
+            return None;
+        }
+        parse_pypi_response(&String::from_utf8_lossy(&output.stdout))
+    }
+
+    fn get_version_from(&self, package: &str, registry: &str) -> Option<String> {
+        // PEP 691 JSON flavour of the simple index, served by pip-compatible mirrors
+        let url = format!(
+            "{}/{}/",
+            registry.trim_end_matches('/'),
+            urlencoding::encode(&normalize_name(package))
+        );
+        let output = Command::new("curl")
+            .args(["-sf", "-m", "10", "-H", "Accept: application/vnd.pypi.simple.v1+json", &url])
+            .output()
+            .ok()?;
+        if !output.status.success() {
+            return None;
+        }
+        parse_simple_index(&String::from_utf8_lossy(&output.stdout))
+    }
+}
+
+/// Normalize a distribution name per PEP 503 (lowercase, runs of `-_.` become `-`)
+#[must_use]
+pub fn normalize_name(name: &str) -> String {
+    let mut out = String::with_capacity(name.len());
+    for c in name.chars() {
+        if matches!(c, '-' | '_' | '.') {
+            if !out.ends_with('-') {
+                out.push('-');
+            }
+        } else {
+            out.push(c.to_ascii_lowercase());
+        }
+    }
+    out
+}
+
+fn parse_pypi_response(json: &str) -> Option<String> {
+    let parsed: serde_json::Value = serde_json::from_str(json).ok()?;
+    parsed.get("info")?.get("version")?.as_str().map(String::from)
+}
+
+fn parse_simple_index(json: &str) -> Option<String> {
+    let parsed: serde_json::Value = serde_json::from_str(json).ok()?;
+    let versions = parsed.get("versions")?.as_array()?;
+
+    // Skip pre-releases and dev releases (1.0a1, 2.0rc1, 3.0.dev2)
+    versions
+        .iter()
+        .filter_map(|v| v.as_str())
+        .filter(|v| v.chars().all(|c| c.is_ascii_digit() || c == '.' || c == '!' || c == '+'))
+        .fold(None, |best: Option<&str>, v| match best {
+            Some(b) if !is_newer(b, v) => Some(b),
+            _ => Some(v),
+        })
+        .map(String::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_name() {
+        assert_eq!(normalize_name("Flask"), "flask");
+        assert_eq!(normalize_name("zope.interface"), "zope-interface");
+        assert_eq!(normalize_name("typing__extensions"), "typing-extensions");
+    }
+
+    #[test]
+    fn test_parse_pypi_response() {
+        let json = r#"{"info":{"name":"requests","version":"2.32.3"}}"#;
+        assert_eq!(parse_pypi_response(json), Some("2.32.3".to_string()));
+    }
+
+    #[test]
+    fn test_parse_simple_index() {
+        let json =
+            r#"{"meta":{"api-version":"1.1"},"versions":["1.9.0","1.10.0","2.0rc1","1.2.0"]}"#;
+        assert_eq!(parse_simple_index(json), Some("1.10.0".to_string()));
+    }
+
+    #[test]
+    fn test_parse_simple_index_no_versions() {
+        assert_eq!(parse_simple_index(r#"{"files":[]}"#), None);
+        assert_eq!(parse_simple_index(r#"{"versions":["1.0.dev1"]}"#), None);
+    }
+
+    #[test]
+    fn test_pypi_source_properties() {
+        let pypi = PypiSource;
+        assert_eq!(pypi.name(), "pypi");
+        assert_eq!(pypi.ecosystem(), Ecosystem::Python);
+        assert!(!pypi.is_local());
+    }
+}
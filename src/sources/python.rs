@@ -0,0 +1,88 @@
+use super::{Ecosystem, Source, is_newer_within};
+use crate::is_newer;
+use serde_json::Value;
+use std::process::Command;
+
+/// `CPython` itself, from python.org's published releases
+pub struct PythonSource;
+
+impl Source for PythonSource {
+    fn name(&self) -> &'static str {
+        "python"
+    }
+
+    fn ecosystem(&self) -> Ecosystem {
+        Ecosystem::System
+    }
+
+    fn get_version(&self, package: &str) -> Option<String> {
+        if !self.answers_for(package) {
+            return None;
+        }
+        let output = Command::new("curl")
+            .args([
+                "-sf",
+                "-m",
+                "10",
+                "https://www.python.org/api/v2/downloads/release/?is_published=true",
+            ])
+            .output()
+            .ok()?;
+        if !output.status.success() {
+            return None;
+        }
+        parse_python_releases(&String::from_utf8_lossy(&output.stdout))
+    }
+
+    fn answers_for(&self, package: &str) -> bool {
+        matches!(package, "python" | "python3" | "cpython")
+    }
+
+    /// `.python-version` pins like `3.12` follow the newest 3.12.x release
+    fn is_newer(&self, installed: &str, latest: &str) -> bool {
+        is_newer_within(installed, latest)
+    }
+}
+
+/// Entries look like `{"name": "Python 3.12.1", "pre_release": false, ...}`
+fn parse_python_releases(json: &str) -> Option<String> {
+    let parsed: Value = serde_json::from_str(json).ok()?;
+    parsed
+        .as_array()?
+        .iter()
+        .filter(|r| r.get("pre_release").and_then(Value::as_bool) == Some(false))
+        .filter_map(|r| r.get("name")?.as_str()?.strip_prefix("Python "))
+        .filter(|v| v.chars().all(|c| c.is_ascii_digit() || c == '.'))
+        .fold(None, |best: Option<&str>, v| match best {
+            Some(b) if !is_newer(b, v) => Some(b),
+            _ => Some(v),
+        })
+        .map(String::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_python_releases() {
+        let json = r#"[
+            {"name":"Python 3.12.8","pre_release":false},
+            {"name":"Python 3.14.0a3","pre_release":true},
+            {"name":"Python 3.13.1","pre_release":false},
+            {"name":"Python 3.9.21","pre_release":false},
+            {"name":"Python 2.7.18","pre_release":false}
+        ]"#;
+        assert_eq!(parse_python_releases(json).as_deref(), Some("3.13.1"));
+    }
+
+    #[test]
+    fn test_python_source_properties() {
+        let python = PythonSource;
+        assert_eq!(python.name(), "python");
+        assert_eq!(python.ecosystem(), Ecosystem::System);
+        assert!(!python.is_local());
+        assert!(!python.is_newer("3.13", "3.13.1"));
+        assert!(python.is_newer("3.12", "3.13.1"));
+    }
+}
@@ -0,0 +1,90 @@
+use super::{Ecosystem, Source, is_newer_within};
+use crate::is_newer;
+use serde_yaml_ng::Value;
+use std::process::Command;
+
+/// Ruby itself, from the release index behind ruby-lang.org
+pub struct RubySource;
+
+impl Source for RubySource {
+    fn name(&self) -> &'static str {
+        "ruby"
+    }
+
+    fn ecosystem(&self) -> Ecosystem {
+        Ecosystem::System
+    }
+
+    fn get_version(&self, package: &str) -> Option<String> {
+        if !self.answers_for(package) {
+            return None;
+        }
+        let output = Command::new("curl")
+            .args([
+                "-sf",
+                "-m",
+                "10",
+                "https://raw.githubusercontent.com/ruby/www.ruby-lang.org/master/_data/releases.yml",
+            ])
+            .output()
+            .ok()?;
+        if !output.status.success() {
+            return None;
+        }
+        parse_ruby_releases(&String::from_utf8_lossy(&output.stdout))
+    }
+
+    fn answers_for(&self, package: &str) -> bool {
+        package == "ruby"
+    }
+
+    /// `.ruby-version` pins like `3.3` follow the newest 3.3.x release
+    fn is_newer(&self, installed: &str, latest: &str) -> bool {
+        is_newer_within(installed, latest)
+    }
+}
+
+/// `- version: 3.3.0` entries; previews and release candidates are skipped
+fn parse_ruby_releases(yaml: &str) -> Option<String> {
+    let parsed: Value = serde_yaml_ng::from_str(yaml).ok()?;
+    parsed
+        .as_sequence()?
+        .iter()
+        .filter_map(|r| match r.get("version")? {
+            Value::String(s) => Some(s.clone()),
+            Value::Number(n) => Some(n.to_string()),
+            _ => None,
+        })
+        .filter(|v| v.chars().all(|c| c.is_ascii_digit() || c == '.'))
+        .fold(None, |best: Option<String>, v| match best {
+            Some(b) if !is_newer(&b, &v) => Some(b),
+            _ => Some(v),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_ruby_releases() {
+        let yaml = r"- version: 3.4.0-preview2
+  date: 2024-10-07
+- version: 3.3.6
+  date: 2024-11-05
+- version: 3.2.6
+  date: 2024-10-30
+- version: 3.3.10
+  date: 2025-01-01
+";
+        assert_eq!(parse_ruby_releases(yaml).as_deref(), Some("3.3.10"));
+    }
+
+    #[test]
+    fn test_ruby_source_properties() {
+        let ruby = RubySource;
+        assert_eq!(ruby.name(), "ruby");
+        assert_eq!(ruby.ecosystem(), Ecosystem::System);
+        assert!(!ruby.is_local());
+    }
+}
@@ -0,0 +1,79 @@
+use super::{Ecosystem, Source, is_newer_within};
+use std::process::Command;
+
+/// The Rust toolchain itself, from rustup's channel manifests: stable by default,
+/// `rust@beta` for the beta channel
+pub struct RustSource;
+
+impl Source for RustSource {
+    fn name(&self) -> &'static str {
+        "rust"
+    }
+
+    fn ecosystem(&self) -> Ecosystem {
+        Ecosystem::System
+    }
+
+    fn get_version(&self, package: &str) -> Option<String> {
+        let channel = package.split_once('@').map_or("stable", |(_, channel)| channel);
+        if !self.answers_for(package) || !matches!(channel, "stable" | "beta") {
+            return None;
+        }
+        let url = format!("https://static.rust-lang.org/dist/channel-rust-{channel}.toml");
+        let output = Command::new("curl").args(["-sf", "-m", "10", &url]).output().ok()?;
+        if !output.status.success() {
+            return None;
+        }
+        parse_channel_manifest(&String::from_utf8_lossy(&output.stdout))
+    }
+
+    fn answers_for(&self, package: &str) -> bool {
+        matches!(package.split('@').next(), Some("rust" | "rustc"))
+    }
+
+    /// `rust-toolchain.toml` pins like `1.75` follow the newest 1.75.x release
+    fn is_newer(&self, installed: &str, latest: &str) -> bool {
+        is_newer_within(installed, latest)
+    }
+}
+
+/// `[pkg.rust] version = "1.75.0 (82e1608df 2023-12-21)"`, or `1.76.0-beta.5 (...)` on beta
+fn parse_channel_manifest(manifest: &str) -> Option<String> {
+    let parsed: toml::Value = toml::from_str(manifest).ok()?;
+    let version = parsed.get("pkg")?.get("rust")?.get("version")?.as_str()?;
+    version.split_whitespace().next().map(String::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_channel_manifest() {
+        let manifest = r#"manifest-version = "2"
+date = "2023-12-28"
+
+[pkg.cargo]
+version = "1.75.0 (1d8b05cdd 2023-11-20)"
+
+[pkg.rust]
+version = "1.75.0 (82e1608df 2023-12-21)"
+
+[pkg.rust.target.x86_64-unknown-linux-gnu]
+available = true
+"#;
+        assert_eq!(parse_channel_manifest(manifest).as_deref(), Some("1.75.0"));
+
+        let beta = "[pkg.rust]\nversion = \"1.76.0-beta.5 (f1b3bc0bb 2024-01-05)\"\n";
+        assert_eq!(parse_channel_manifest(beta).as_deref(), Some("1.76.0-beta.5"));
+    }
+
+    #[test]
+    fn test_rust_source_properties() {
+        let rust = RustSource;
+        assert_eq!(rust.name(), "rust");
+        assert_eq!(rust.ecosystem(), Ecosystem::System);
+        assert!(!rust.is_local());
+        assert_eq!(rust.get_version("rust@nightly"), None);
+    }
+}
@@ -0,0 +1,170 @@
+use super::{Ecosystem, Source, highest_version, matches_requirement};
+use serde_json::Value;
+use std::process::Command;
+
+const DEFAULT_REGISTRY: &str = "registry.terraform.io";
+
+/// Terraform and `OpenTofu` registries: providers are `namespace/type`
+/// (`hashicorp/aws`), modules `namespace/name/provider` (`terraform-aws-modules/vpc/aws`)
+pub struct TerraformSource;
+
+impl Source for TerraformSource {
+    fn name(&self) -> &'static str {
+        "terraform"
+    }
+
+    fn ecosystem(&self) -> Ecosystem {
+        Ecosystem::Terraform
+    }
+
+    fn get_version(&self, package: &str) -> Option<String> {
+        highest(&self.get_versions(package)?)
+    }
+
+    fn get_version_from(&self, package: &str, registry: &str) -> Option<String> {
+        highest(&self.get_versions_from(package, registry)?)
+    }
+
+    fn get_versions(&self, package: &str) -> Option<Vec<String>> {
+        match package.split_once('/') {
+            Some((host, rest)) if host.contains('.') => self.get_versions_from(rest, host),
+            _ => versions(&format!("https://{DEFAULT_REGISTRY}"), &default_paths(), package),
+        }
+    }
+
+    fn get_versions_from(&self, package: &str, registry: &str) -> Option<Vec<String>> {
+        let host = registry.split_once("://").map_or(registry, |(_, rest)| rest);
+        let base = format!("https://{}", host.trim_end_matches('/'));
+        versions(&base, &discover(&base).unwrap_or_else(default_paths), package)
+    }
+
+    fn satisfies(&self, version: &str, requirement: &str) -> Option<bool> {
+        matches_requirement(version, requirement)
+    }
+}
+
+/// API base paths for (providers, modules)
+fn default_paths() -> (String, String) {
+    ("/v1/providers/".to_string(), "/v1/modules/".to_string())
+}
+
+/// Registry service discovery: private registries (e.g. Terraform Cloud) serve the
+/// API under their own paths, listed in `/.well-known/terraform.json`
+fn discover(base: &str) -> Option<(String, String)> {
+    let json = curl(&format!("{base}/.well-known/terraform.json"))?;
+    parse_discovery(&json)
+}
+
+fn parse_discovery(json: &str) -> Option<(String, String)> {
+    let parsed: Value = serde_json::from_str(json).ok()?;
+    let path = |key: &str| parsed.get(key).and_then(Value::as_str).map(String::from);
+    let (defaults_providers, defaults_modules) = default_paths();
+    Some((
+        path("providers.v1").unwrap_or(defaults_providers),
+        path("modules.v1").unwrap_or(defaults_modules),
+    ))
+}
+
+fn versions(
+    base: &str,
+    (providers, modules): &(String, String),
+    package: &str,
+) -> Option<Vec<String>> {
+    let parts = package.split('/').count();
+    let api = match parts {
+        2 => providers,
+        3 => modules,
+        _ => return None,
+    };
+    // A relative path hangs off the registry host; an absolute URL stands alone
+    let prefix = if api.starts_with("http") { api.clone() } else { format!("{base}{api}") };
+    let json = curl(&format!("{}/{package}/versions", prefix.trim_end_matches('/')))?;
+    if parts == 2 { parse_provider_versions(&json) } else { parse_module_versions(&json) }
+}
+
+fn curl(url: &str) -> Option<String> {
+    let output = Command::new("curl").args(["-sf", "-m", "10", url]).output().ok()?;
+    output.status.success().then(|| String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// `{"versions": [{"version": "5.31.0", ...}]}`
+fn parse_provider_versions(json: &str) -> Option<Vec<String>> {
+    let parsed: Value = serde_json::from_str(json).ok()?;
+    Some(stable(parsed.get("versions")?.as_array()?))
+}
+
+/// `{"modules": [{"versions": [{"version": "5.1.2"}]}]}`
+fn parse_module_versions(json: &str) -> Option<Vec<String>> {
+    let parsed: Value = serde_json::from_str(json).ok()?;
+    Some(stable(parsed.get("modules")?.get(0)?.get("versions")?.as_array()?))
+}
+
+/// Pre-releases (`6.0.0-beta1`) are skipped
+fn stable(versions: &[Value]) -> Vec<String> {
+    versions
+        .iter()
+        .filter_map(|v| v.get("version")?.as_str())
+        .filter(|v| !v.contains('-'))
+        .map(String::from)
+        .collect()
+}
+
+/// Registries list versions unordered
+fn highest(versions: &[String]) -> Option<String> {
+    highest_version(versions.iter().map(String::as_str)).map(String::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_provider_versions() {
+        let json = r#"{"id":"hashicorp/aws","versions":[{"version":"5.9.0"},{"version":"5.31.0"},
+            {"version":"6.0.0-beta1"},{"version":"4.67.0"}]}"#;
+        let versions = parse_provider_versions(json).unwrap_or_default();
+        assert_eq!(versions, vec!["5.9.0", "5.31.0", "4.67.0"]);
+        assert_eq!(highest(&versions), Some("5.31.0".to_string()));
+        assert_eq!(parse_provider_versions(r#"{"versions":[]}"#).and_then(|v| highest(&v)), None);
+    }
+
+    #[test]
+    fn test_parse_module_versions() {
+        let json = r#"{"modules":[{"source":"terraform-aws-modules/vpc/aws",
+            "versions":[{"version":"5.1.2"},{"version":"5.10.0"},{"version":"5.2.0"}]}]}"#;
+        assert_eq!(
+            parse_module_versions(json).and_then(|v| highest(&v)),
+            Some("5.10.0".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_discovery() {
+        let json = r#"{"modules.v1":"/api/registry/v1/modules/","providers.v1":"/api/registry/v1/providers/"}"#;
+        assert_eq!(
+            parse_discovery(json),
+            Some((
+                "/api/registry/v1/providers/".to_string(),
+                "/api/registry/v1/modules/".to_string()
+            ))
+        );
+        assert_eq!(parse_discovery("{}"), Some(default_paths()));
+    }
+
+    #[test]
+    fn test_terraform_constraints() {
+        let terraform = TerraformSource;
+        assert_eq!(terraform.satisfies("5.31.0", "~> 5.0"), Some(true));
+        assert_eq!(terraform.satisfies("5.31.0", "~> 5.0.0"), Some(false));
+        assert_eq!(terraform.satisfies("4.67.0", ">= 4.0, < 5.0"), Some(true));
+        assert_eq!(terraform.satisfies("5.1.2", "5.1.2"), Some(true));
+    }
+
+    #[test]
+    fn test_terraform_source_properties() {
+        let terraform = TerraformSource;
+        assert_eq!(terraform.name(), "terraform");
+        assert_eq!(terraform.ecosystem(), Ecosystem::Terraform);
+        assert!(!terraform.is_local());
+    }
+}
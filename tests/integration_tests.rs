@@ -1,3 +1,4 @@
+
 use std::process::Command;
 
 fn latest_cmd() -> Command {
@@ -117,8 +118,8 @@ fn test_cargo_serde() {
     assert!(output.status.success());
     let stdout = String::from_utf8_lossy(&output.stdout).trim().to_string();
     // Should contain source and version
-    assert!(stdout.contains("cargo:"), "Expected source prefix: {}", stdout);
-    assert!(stdout.contains('.'), "Expected version with dots: {}", stdout);
+    assert!(stdout.contains("cargo:"), "Expected source prefix: {stdout}");
+    assert!(stdout.contains('.'), "Expected version with dots: {stdout}");
 }
 
 #[test]
@@ -127,7 +128,7 @@ fn test_npm_express() {
 
     assert!(output.status.success());
     let stdout = String::from_utf8_lossy(&output.stdout).trim().to_string();
-    assert!(stdout.contains('.'), "Expected version with dots: {}", stdout);
+    assert!(stdout.contains('.'), "Expected version with dots: {stdout}");
 }
 
 #[test]
@@ -144,8 +145,8 @@ fn test_mixed_found_and_not_found() {
     let stderr = String::from_utf8_lossy(&output.stderr);
 
     // But should still output the found one
-    assert!(stdout.contains("serde:"), "stdout: {}", stdout);
-    assert!(stderr.contains("not found"), "stderr: {}", stderr);
+    assert!(stdout.contains("serde:"), "stdout: {stdout}");
+    assert!(stderr.contains("not found"), "stderr: {stderr}");
 }
 
 #[test]
@@ -176,8 +177,8 @@ fn test_prefix_syntax() {
     assert!(output.status.success());
     let stdout = String::from_utf8_lossy(&output.stdout).trim().to_string();
     // Should show npm source
-    assert!(stdout.contains("npm:"), "Expected npm source: {}", stdout);
-    assert!(stdout.contains('.'), "Expected version with dots: {}", stdout);
+    assert!(stdout.contains("npm:"), "Expected npm source: {stdout}");
+    assert!(stdout.contains('.'), "Expected version with dots: {stdout}");
 }
 
 #[test]
@@ -186,7 +187,7 @@ fn test_prefix_syntax_cargo() {
 
     assert!(output.status.success());
     let stdout = String::from_utf8_lossy(&output.stdout).trim().to_string();
-    assert!(stdout.contains("cargo:"), "Expected cargo source: {}", stdout);
+    assert!(stdout.contains("cargo:"), "Expected cargo source: {stdout}");
 }
 
 #[test]
@@ -196,7 +197,7 @@ fn test_source_in_output() {
     assert!(output.status.success());
     let stdout = String::from_utf8_lossy(&output.stdout).trim().to_string();
     // Output should always include source prefix
-    assert!(stdout.starts_with("npm:"), "Output should start with source: {}", stdout);
+    assert!(stdout.starts_with("npm:"), "Output should start with source: {stdout}");
 }
 
 #[test]